@@ -0,0 +1,182 @@
+use crate::block::blowfish::tables::{P_INIT, S_INIT};
+use crate::block::BlockCipher;
+
+pub const MIN_KEY_LEN: usize = 4;
+pub const MAX_KEY_LEN: usize = 56;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum BlowfishError {
+    InvalidKeyLength(usize),
+}
+
+/// Blowfish with 64-bit blocks; the key schedule runs once in `new`.
+#[derive(Clone)]
+pub struct Blowfish {
+    p: [u32; 18],
+    s: [[u32; 256]; 4],
+}
+
+impl Blowfish {
+    pub fn new(key: &[u8]) -> Result<Self, BlowfishError> {
+        if key.len() < MIN_KEY_LEN || key.len() > MAX_KEY_LEN {
+            return Err(BlowfishError::InvalidKeyLength(key.len()));
+        }
+        let mut cipher = Blowfish::initial_state();
+        cipher.expand_key(&[], key);
+        Ok(cipher)
+    }
+
+    pub fn encrypt_block(&self, block: [u8; 8]) -> [u8; 8] {
+        let (left, right) = split_block(block);
+        let (left, right) = self.encrypt_words(left, right);
+        join_block(left, right)
+    }
+
+    pub fn decrypt_block(&self, block: [u8; 8]) -> [u8; 8] {
+        let (left, right) = split_block(block);
+        let (left, right) = self.decrypt_words(left, right);
+        join_block(left, right)
+    }
+
+    pub(crate) fn initial_state() -> Self {
+        Blowfish {
+            p: P_INIT,
+            s: S_INIT,
+        }
+    }
+
+    // Mezcla la clave en P y vuelve a cifrar todo el estado; con sal (Eksblowfish)
+    // cada bloque se combina antes con los siguientes 64 bits de la sal.
+    pub(crate) fn expand_key(&mut self, salt: &[u8], key: &[u8]) {
+        let mut key_pos = 0;
+        for word in self.p.iter_mut() {
+            *word ^= next_word(key, &mut key_pos);
+        }
+
+        let mut salt_pos = 0;
+        let mut left = 0u32;
+        let mut right = 0u32;
+        let mut next_block = |cipher: &Blowfish| {
+            if !salt.is_empty() {
+                left ^= next_word(salt, &mut salt_pos);
+                right ^= next_word(salt, &mut salt_pos);
+            }
+            (left, right) = cipher.encrypt_words(left, right);
+            (left, right)
+        };
+
+        for i in (0..18).step_by(2) {
+            (self.p[i], self.p[i + 1]) = next_block(self);
+        }
+        for sbox in 0..4 {
+            for i in (0..256).step_by(2) {
+                (self.s[sbox][i], self.s[sbox][i + 1]) = next_block(self);
+            }
+        }
+    }
+
+    pub(crate) fn encrypt_words(&self, mut left: u32, mut right: u32) -> (u32, u32) {
+        for i in 0..16 {
+            left ^= self.p[i];
+            right ^= self.feistel(left);
+            std::mem::swap(&mut left, &mut right);
+        }
+        std::mem::swap(&mut left, &mut right);
+        (left ^ self.p[17], right ^ self.p[16])
+    }
+
+    fn decrypt_words(&self, mut left: u32, mut right: u32) -> (u32, u32) {
+        for i in (2..18).rev() {
+            left ^= self.p[i];
+            right ^= self.feistel(left);
+            std::mem::swap(&mut left, &mut right);
+        }
+        std::mem::swap(&mut left, &mut right);
+        (left ^ self.p[0], right ^ self.p[1])
+    }
+
+    fn feistel(&self, x: u32) -> u32 {
+        let [a, b, c, d] = x.to_be_bytes();
+        (self.s[0][a as usize].wrapping_add(self.s[1][b as usize]) ^ self.s[2][c as usize])
+            .wrapping_add(self.s[3][d as usize])
+    }
+}
+
+impl BlockCipher for Blowfish {
+    const BLOCK_SIZE: usize = 8;
+
+    fn encrypt_block_mut(&self, block: &mut [u8]) {
+        let output = self.encrypt_block(block.try_into().expect("Blowfish blocks are 8 bytes"));
+        block.copy_from_slice(&output);
+    }
+
+    fn decrypt_block_mut(&self, block: &mut [u8]) {
+        let output = self.decrypt_block(block.try_into().expect("Blowfish blocks are 8 bytes"));
+        block.copy_from_slice(&output);
+    }
+}
+
+// Lee 32 bits big-endian tratando los datos como un flujo cíclico.
+fn next_word(data: &[u8], pos: &mut usize) -> u32 {
+    let mut word = 0u32;
+    for _ in 0..4 {
+        word = (word << 8) | data[*pos] as u32;
+        *pos = (*pos + 1) % data.len();
+    }
+    word
+}
+
+fn split_block(block: [u8; 8]) -> (u32, u32) {
+    let data = u64::from_be_bytes(block);
+    ((data >> 32) as u32, data as u32)
+}
+
+fn join_block(left: u32, right: u32) -> [u8; 8] {
+    (((left as u64) << 32) | right as u64).to_be_bytes()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encrypt_hex(key: u64, plaintext: u64) -> u64 {
+        let cipher = Blowfish::new(&key.to_be_bytes()).unwrap();
+        u64::from_be_bytes(cipher.encrypt_block(plaintext.to_be_bytes()))
+    }
+
+    #[test]
+    fn test_schneier_vectors() {
+        assert_eq!(encrypt_hex(0x0000000000000000, 0x0000000000000000), 0x4EF997456198DD78);
+        assert_eq!(encrypt_hex(0xFFFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFFFF), 0x51866FD5B85ECB8A);
+        assert_eq!(encrypt_hex(0x3000000000000000, 0x1000000000000001), 0x7D856F9A613063F2);
+        assert_eq!(encrypt_hex(0x0123456789ABCDEF, 0x1111111111111111), 0x61F9C3802281B096);
+        assert_eq!(encrypt_hex(0xFEDCBA9876543210, 0x0123456789ABCDEF), 0x0ACEAB0FC6A0A28D);
+    }
+
+    #[test]
+    fn test_variable_key_length() {
+        let key: Vec<u8> = [0xF0E1D2C3B4A59687u64, 0x78695A4B3C2D1E0F, 0x0011223344556677]
+            .iter()
+            .flat_map(|word| word.to_be_bytes())
+            .collect();
+        let cipher = Blowfish::new(&key).unwrap();
+        assert_eq!(
+            cipher.encrypt_block(0xFEDCBA9876543210u64.to_be_bytes()),
+            0x05044B62FA52D080u64.to_be_bytes()
+        );
+    }
+
+    #[test]
+    fn test_decrypt_inverts_encrypt() {
+        let cipher = Blowfish::new(b"a longer blowfish key").unwrap();
+        let block = *b"8 bytes!";
+        assert_eq!(cipher.decrypt_block(cipher.encrypt_block(block)), block);
+    }
+
+    #[test]
+    fn test_invalid_key_length() {
+        assert!(matches!(Blowfish::new(b"abc"), Err(BlowfishError::InvalidKeyLength(3))));
+        assert!(matches!(Blowfish::new(&[0u8; 57]), Err(BlowfishError::InvalidKeyLength(57))));
+    }
+}
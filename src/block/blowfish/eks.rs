@@ -0,0 +1,13 @@
+use crate::block::blowfish::Blowfish;
+
+/// Eksblowfish setup: the salted key schedule followed by 2^cost rounds of
+/// alternately re-keying with the key and the salt.
+pub fn eks_blowfish_setup(cost: u32, salt: &[u8; 16], key: &[u8]) -> Blowfish {
+    let mut state = Blowfish::initial_state();
+    state.expand_key(salt, key);
+    for _ in 0..1u64 << cost {
+        state.expand_key(&[], key);
+        state.expand_key(&[], salt);
+    }
+    state
+}
@@ -0,0 +1,5 @@
+pub mod cipher;
+pub mod eks;
+pub mod tables;
+
+pub use cipher::{Blowfish, BlowfishError};
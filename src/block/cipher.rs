@@ -0,0 +1,14 @@
+/// A block cipher that transforms one `BLOCK_SIZE`-byte block in place.
+pub trait BlockCipher {
+    const BLOCK_SIZE: usize;
+
+    /// # Panics
+    ///
+    /// Panics if `block` is not exactly `BLOCK_SIZE` bytes long.
+    fn encrypt_block_mut(&self, block: &mut [u8]);
+
+    /// # Panics
+    ///
+    /// Panics if `block` is not exactly `BLOCK_SIZE` bytes long.
+    fn decrypt_block_mut(&self, block: &mut [u8]);
+}
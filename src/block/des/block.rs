@@ -4,7 +4,7 @@ use std::convert::TryInto;
 use crate::block::des::key::Key;
 use crate::block::des::tables::*;
 
-struct DesBlock {
+pub(crate) struct DesBlock {
     data: u64,
 }
 
@@ -29,26 +29,36 @@ impl FromStr for DesBlock {
 
 impl DesBlock {
     pub fn encrypt_block(&self, key: &Key) -> Self {
+        self.encrypt_with_subkeys(&key.generate_subkeys())
+    }
+
+    pub fn decrypt_block(&self, key: &Key) -> Self {
+        self.decrypt_with_subkeys(&key.generate_subkeys())
+    }
+
+    pub(crate) fn encrypt_with_subkeys(&self, subkeys: &[Key; 16]) -> Self {
         let mut result = self.init_permutation();
-        let subkeys = key.generate_subkeys();
         for subkey in subkeys {
-            result = result.round_des(subkey);
+            result = result.round_des(*subkey);
         }
 
         result = result.bits_swap32().inverse_permutation();
         result
     }
 
-    pub fn decrypt_block(&self, key: &Key) -> Self {
+    pub(crate) fn decrypt_with_subkeys(&self, subkeys: &[Key; 16]) -> Self {
         let mut result = self.init_permutation();
-        let subkeys = key.generate_subkeys();
         for subkey in subkeys.iter().rev() {
-            result = result.round_des(subkey.clone());
+            result = result.round_des(*subkey);
         }
         result = result.bits_swap32().inverse_permutation();
         result
     }
 
+    pub(crate) fn get_data(&self) -> u64 {
+        self.data
+    }
+
     fn round_des(&self, key: Key) -> Self {
     let right = DesBlock { data: self.data & 0xFFFFFFFF };
     let left = DesBlock { data: self.data >> 32 };
@@ -0,0 +1,82 @@
+use crate::block::des::block::DesBlock;
+use crate::block::des::key::Key;
+use crate::block::BlockCipher;
+
+/// DES over 8-byte blocks; the 16 round keys are derived once in `new`.
+#[derive(Clone)]
+pub struct Des {
+    subkeys: [Key; 16],
+}
+
+impl Des {
+    pub fn new(key: &Key) -> Self {
+        Des {
+            subkeys: key.generate_subkeys(),
+        }
+    }
+
+    pub fn encrypt_block(&self, block: [u8; 8]) -> [u8; 8] {
+        let input = DesBlock::from_64bits_number(u64::from_be_bytes(block));
+        input.encrypt_with_subkeys(&self.subkeys).get_data().to_be_bytes()
+    }
+
+    pub fn decrypt_block(&self, block: [u8; 8]) -> [u8; 8] {
+        let input = DesBlock::from_64bits_number(u64::from_be_bytes(block));
+        input.decrypt_with_subkeys(&self.subkeys).get_data().to_be_bytes()
+    }
+}
+
+impl BlockCipher for Des {
+    const BLOCK_SIZE: usize = 8;
+
+    fn encrypt_block_mut(&self, block: &mut [u8]) {
+        let output = self.encrypt_block(block.try_into().expect("DES blocks are 8 bytes"));
+        block.copy_from_slice(&output);
+    }
+
+    fn decrypt_block_mut(&self, block: &mut [u8]) {
+        let output = self.decrypt_block(block.try_into().expect("DES blocks are 8 bytes"));
+        block.copy_from_slice(&output);
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encrypt(key: u64, plaintext: u64) -> u64 {
+        let des = Des::new(&Key::from_bytes(key.to_be_bytes()));
+        u64::from_be_bytes(des.encrypt_block(plaintext.to_be_bytes()))
+    }
+
+    #[test]
+    fn test_known_answers() {
+        assert_eq!(encrypt(0x133457799BBCDFF1, 0x0123456789ABCDEF), 0x85E813540F0AB405);
+        assert_eq!(encrypt(0x0E329232EA6D0D73, 0x8787878787878787), 0x0000000000000000);
+        assert_eq!(encrypt(0xAABB09182736CCDD, 0x123456ABCD132536), 0xC0B7A8D05F3A829C);
+    }
+
+    #[test]
+    fn test_arbitrary_key_bytes_round_trip() {
+        let key = Key::from([0x00, 0xff, 0x80, 0x7f, 0x01, 0xfe, 0x10, 0xef]);
+        assert_eq!(key.to_bytes(), [0x00, 0xff, 0x80, 0x7f, 0x01, 0xfe, 0x10, 0xef]);
+
+        let des = Des::new(&key);
+        let block = [0xde, 0xad, 0xbe, 0xef, 0x00, 0x01, 0x02, 0x03];
+        let encrypted = des.encrypt_block(block);
+        assert_ne!(encrypted, block);
+        assert_eq!(des.decrypt_block(encrypted), block);
+    }
+
+    #[test]
+    fn test_matches_per_call_key_schedule() {
+        let key = Key::from_64bits_number(0xAABB09182736CCDD);
+        let des = Des::new(&key);
+        let block = DesBlock::from_64bits_number(0x123456ABCD132536);
+        assert_eq!(
+            des.encrypt_block(0x123456ABCD132536u64.to_be_bytes()),
+            block.encrypt_block(&key).get_data().to_be_bytes()
+        );
+    }
+}
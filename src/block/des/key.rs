@@ -1,28 +1,49 @@
 use std::str::FromStr;
 
-#[derive(Copy, Clone, Debug)]
+use rand::RngCore;
+
+use crate::block::des::tables::{SEMI_WEAK_KEYS, WEAK_KEYS};
+use crate::kdf::{pbkdf2_hmac_sha256, Pbkdf2Error};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct Key {
     data: u64,
 }
 
+#[derive(Debug, PartialEq, Eq)]
+pub enum KeyError {
+    InvalidLength(usize),
+    NonAscii,
+    // Índice del primer byte con paridad par.
+    InvalidParity(usize),
+    WeakKey,
+    SemiWeakKey,
+}
+
 impl FromStr for Key {
-    type Err = ();
+    type Err = KeyError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         if s.len() != 8 {
-            return Err(());
+            return Err(KeyError::InvalidLength(s.len()));
         }
 
         if !s.is_ascii() {
-            return Err(());
+            return Err(KeyError::NonAscii);
         }
 
-        let bytes: [u8; 8] = s.as_bytes().try_into().map_err(|_| ())?;
+        let bytes: [u8; 8] = s.as_bytes().try_into().expect("length checked above");
         let data = u64::from_be_bytes(bytes);
         Ok(Key { data })
     }
 }
 
+impl From<[u8; 8]> for Key {
+    fn from(bytes: [u8; 8]) -> Self {
+        Key::from_bytes(bytes)
+    }
+}
+
 impl Key {
     pub fn get_data(&self) -> u64 {
         self.data
@@ -124,12 +145,79 @@ impl Key {
         }
     }
 
+    pub fn from_bytes(bytes: [u8; 8]) -> Self {
+        Key::from_64bits_number(u64::from_be_bytes(bytes))
+    }
+
+    pub fn to_bytes(&self) -> [u8; 8] {
+        self.data.to_be_bytes()
+    }
+
     pub fn from_64bits_number(number:u64) -> Self{
         Key{
             data: number
         }
     }
 
+    // Deriva los 64 bits de la clave con PBKDF2-HMAC-SHA256 en lugar de usarlos tal cual.
+    pub fn from_password(password: &[u8], salt: &[u8], iterations: u32) -> Result<Self, Pbkdf2Error> {
+        let derived = pbkdf2_hmac_sha256(password, salt, iterations, 8)?;
+        let bytes: [u8; 8] = derived.try_into().expect("PBKDF2 returned 8 bytes");
+        Ok(Key::from_bytes(bytes))
+    }
+
+    // Clave aleatoria con paridad impar que nunca es débil ni semidébil.
+    pub fn random() -> Self {
+        let mut rng = rand::thread_rng();
+        loop {
+            let key = Key::from_64bits_number(rng.next_u64()).with_odd_parity();
+            if !key.is_weak() && !key.is_semi_weak() {
+                return key;
+            }
+        }
+    }
+
+    // Cada byte lleva 7 bits de clave y uno de paridad que lo deja con un número impar de unos.
+    pub fn check_parity(&self) -> Result<(), KeyError> {
+        match self.to_bytes().iter().position(|byte| byte.count_ones() % 2 == 0) {
+            Some(index) => Err(KeyError::InvalidParity(index)),
+            None => Ok(()),
+        }
+    }
+
+    pub fn has_odd_parity(&self) -> bool {
+        self.check_parity().is_ok()
+    }
+
+    // Recalcula el bit de paridad de cada byte sin tocar los 56 bits de clave.
+    pub fn with_odd_parity(&self) -> Self {
+        let bytes = self.to_bytes().map(|byte| {
+            let key_bits = byte & 0xFE;
+            key_bits | (key_bits.count_ones() % 2 == 0) as u8
+        });
+        Key::from_bytes(bytes)
+    }
+
+    // La comparación ignora los bits de paridad, que DES no usa.
+    pub fn is_weak(&self) -> bool {
+        WEAK_KEYS.contains(&self.with_odd_parity().data)
+    }
+
+    pub fn is_semi_weak(&self) -> bool {
+        SEMI_WEAK_KEYS.contains(&self.with_odd_parity().data)
+    }
+
+    pub fn validate(&self) -> Result<(), KeyError> {
+        self.check_parity()?;
+        if self.is_weak() {
+            return Err(KeyError::WeakKey);
+        }
+        if self.is_semi_weak() {
+            return Err(KeyError::SemiWeakKey);
+        }
+        Ok(())
+    }
+
     pub fn to_hex_string(&self) -> String {
         let mut result = String::with_capacity(16);
         let input = self.data;
@@ -142,3 +230,67 @@ impl Key {
         result
     }
 }
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::block::des::Des;
+
+    #[test]
+    fn test_from_str_errors() {
+        assert_eq!("abcdefgh".parse::<Key>().unwrap().to_bytes(), *b"abcdefgh");
+        assert_eq!("short".parse::<Key>(), Err(KeyError::InvalidLength(5)));
+        assert_eq!("clave_ñ".parse::<Key>(), Err(KeyError::NonAscii));
+    }
+
+    #[test]
+    fn test_parity() {
+        let key = Key::from_64bits_number(0x133457799BBCDFF1);
+        assert!(key.has_odd_parity());
+
+        let broken = Key::from_64bits_number(0x133457789BBCDFF1);
+        assert_eq!(broken.check_parity(), Err(KeyError::InvalidParity(3)));
+        assert_eq!(broken.with_odd_parity(), key);
+        assert_eq!(Key::from_64bits_number(0).with_odd_parity().get_data(), 0x0101010101010101);
+
+        // Corregir la paridad no cambia el cifrado.
+        let block = *b"parity!!";
+        assert_eq!(Des::new(&broken).encrypt_block(block), Des::new(&key).encrypt_block(block));
+    }
+
+    #[test]
+    fn test_weak_keys_are_involutions() {
+        let block = *b"weak key";
+        for &data in &WEAK_KEYS {
+            let key = Key::from_64bits_number(data);
+            assert!(key.is_weak());
+            assert_eq!(key.validate(), Err(KeyError::WeakKey));
+            let des = Des::new(&key);
+            assert_eq!(des.encrypt_block(des.encrypt_block(block)), block);
+        }
+        // Con los bits de paridad a cero sigue siendo débil.
+        assert!(Key::from_64bits_number(0).is_weak());
+    }
+
+    #[test]
+    fn test_semi_weak_pairs_undo_each_other() {
+        let block = *b"semiweak";
+        for pair in SEMI_WEAK_KEYS.chunks_exact(2) {
+            let first = Key::from_64bits_number(pair[0]);
+            let second = Key::from_64bits_number(pair[1]);
+            assert_eq!(first.validate(), Err(KeyError::SemiWeakKey));
+            assert!(second.is_semi_weak() && !second.is_weak());
+            let encrypted = Des::new(&first).encrypt_block(block);
+            assert_eq!(Des::new(&second).encrypt_block(encrypted), block);
+        }
+    }
+
+    #[test]
+    fn test_random_keys_are_valid() {
+        for _ in 0..64 {
+            assert_eq!(Key::random().validate(), Ok(()));
+        }
+        assert_ne!(Key::random(), Key::random());
+    }
+}
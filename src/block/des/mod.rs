@@ -1,3 +1,8 @@
 pub mod block;
+pub mod cipher;
 pub mod key;
-pub mod tables;
\ No newline at end of file
+pub mod tables;
+pub mod triple;
+
+pub use cipher::Des;
+pub use triple::{TripleDes, TripleDesError};
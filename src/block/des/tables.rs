@@ -81,4 +81,22 @@ pub const IP_INVERSE_TABLE: [u8; 64] = [
         35, 3, 43, 11, 51, 19, 59, 27,
         34, 2, 42, 10, 50, 18, 58, 26,
         33, 1, 41, 9, 49, 17, 57, 25,
-    ];
\ No newline at end of file
+    ];
+
+// Claves débiles: todas las subclaves son iguales, así que cifrar dos veces devuelve el texto.
+pub const WEAK_KEYS: [u64; 4] = [
+    0x0101010101010101,
+    0xFEFEFEFEFEFEFEFE,
+    0xE0E0E0E0F1F1F1F1,
+    0x1F1F1F1F0E0E0E0E,
+];
+
+// Claves semidébiles, por parejas: cifrar con una deshace el cifrado con la otra.
+pub const SEMI_WEAK_KEYS: [u64; 12] = [
+    0x01FE01FE01FE01FE, 0xFE01FE01FE01FE01,
+    0x1FE01FE00EF10EF1, 0xE01FE01FF10EF10E,
+    0x01E001E001F101F1, 0xE001E001F101F101,
+    0x1FFE1FFE0EFE0EFE, 0xFE1FFE1FFE0EFE0E,
+    0x011F011F010E010E, 0x1F011F010E010E01,
+    0xE0FEE0FEF1FEF1FE, 0xFEE0FEE0FEF1FEF1,
+];
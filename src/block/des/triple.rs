@@ -0,0 +1,171 @@
+use crate::block::des::key::Key;
+use crate::block::des::Des;
+use crate::block::BlockCipher;
+
+// El bit menos significativo de cada byte es de paridad y DES lo ignora.
+const PARITY_MASK: u64 = 0xFEFE_FEFE_FEFE_FEFE;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum TripleDesError {
+    InvalidKeyLength(usize),
+    // K1 == K2 o K2 == K3: dos pasadas se cancelan y queda DES simple.
+    DegenerateKeys,
+}
+
+/// Triple DES in EDE form (SP 800-67): encrypt with K1, decrypt with K2, encrypt with K3.
+#[derive(Clone)]
+pub struct TripleDes {
+    k1: Des,
+    k2: Des,
+    k3: Des,
+}
+
+impl TripleDes {
+    // Opción 1: tres claves independientes.
+    pub fn new_three_key(k1: &Key, k2: &Key, k3: &Key) -> Result<Self, TripleDesError> {
+        if same_key(k1, k2) || same_key(k2, k3) {
+            return Err(TripleDesError::DegenerateKeys);
+        }
+        Ok(TripleDes::with_keys(k1, k2, k3))
+    }
+
+    // Opción 2: K3 = K1.
+    pub fn new_two_key(k1: &Key, k2: &Key) -> Result<Self, TripleDesError> {
+        if same_key(k1, k2) {
+            return Err(TripleDesError::DegenerateKeys);
+        }
+        Ok(TripleDes::with_keys(k1, k2, k1))
+    }
+
+    // Opción 3: K1 = K2 = K3, equivalente a DES simple; solo por compatibilidad.
+    pub fn new_single_key(key: &Key) -> Self {
+        TripleDes::with_keys(key, key, key)
+    }
+
+    // 24 bytes eligen la opción 1, 16 la opción 2 y 8 la opción 3.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, TripleDesError> {
+        let keys: Vec<Key> = bytes
+            .chunks_exact(8)
+            .map(|chunk| Key::from_bytes(chunk.try_into().unwrap()))
+            .collect();
+        match bytes.len() {
+            24 => TripleDes::new_three_key(&keys[0], &keys[1], &keys[2]),
+            16 => TripleDes::new_two_key(&keys[0], &keys[1]),
+            8 => Ok(TripleDes::new_single_key(&keys[0])),
+            length => Err(TripleDesError::InvalidKeyLength(length)),
+        }
+    }
+
+    fn with_keys(k1: &Key, k2: &Key, k3: &Key) -> Self {
+        TripleDes {
+            k1: Des::new(k1),
+            k2: Des::new(k2),
+            k3: Des::new(k3),
+        }
+    }
+
+    pub fn encrypt_block(&self, block: [u8; 8]) -> [u8; 8] {
+        let block = self.k1.encrypt_block(block);
+        let block = self.k2.decrypt_block(block);
+        self.k3.encrypt_block(block)
+    }
+
+    pub fn decrypt_block(&self, block: [u8; 8]) -> [u8; 8] {
+        let block = self.k3.decrypt_block(block);
+        let block = self.k2.encrypt_block(block);
+        self.k1.decrypt_block(block)
+    }
+}
+
+fn same_key(a: &Key, b: &Key) -> bool {
+    a.get_data() & PARITY_MASK == b.get_data() & PARITY_MASK
+}
+
+impl BlockCipher for TripleDes {
+    const BLOCK_SIZE: usize = 8;
+
+    fn encrypt_block_mut(&self, block: &mut [u8]) {
+        let output = self.encrypt_block(block.try_into().expect("DES blocks are 8 bytes"));
+        block.copy_from_slice(&output);
+    }
+
+    fn decrypt_block_mut(&self, block: &mut [u8]) {
+        let output = self.decrypt_block(block.try_into().expect("DES blocks are 8 bytes"));
+        block.copy_from_slice(&output);
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::block::modes::test_vectors::{des, from_hex, KEY};
+    use crate::block::modes::{Cbc, Ecb};
+    use crate::block::padding::Padding;
+
+    // Ejemplo de SP 800-67 (la errata "qufck" es del propio documento).
+    const PLAINTEXT: &[u8; 24] = b"The qufck brown fox jump";
+    const KEYS: &str = "0123456789abcdef23456789abcdef01456789abcdef0123";
+
+    #[test]
+    fn test_sp800_67_three_key_ecb() {
+        let ecb = Ecb::new(TripleDes::from_bytes(&from_hex(KEYS)).unwrap());
+        let expected = from_hex("a826fd8ce53b855fcce21c8112256fe668d5c05dd9b6b900");
+        assert_eq!(ecb.encrypt(PLAINTEXT).unwrap(), expected);
+        assert_eq!(ecb.decrypt(&expected).unwrap(), PLAINTEXT);
+    }
+
+    #[test]
+    fn test_two_key_ecb() {
+        let ecb = Ecb::new(TripleDes::from_bytes(&from_hex(&KEYS[..32])).unwrap());
+        let expected = from_hex("c44862f70cf2fbdc9077d0909fa91b884cabd61fc58e0cbb");
+        assert_eq!(ecb.encrypt(PLAINTEXT).unwrap(), expected);
+        assert_eq!(ecb.decrypt(&expected).unwrap(), PLAINTEXT);
+    }
+
+    #[test]
+    fn test_cbc() {
+        let iv = from_hex("f69f2445df4f9b17");
+        let cbc = Cbc::new(TripleDes::from_bytes(&from_hex(KEYS)).unwrap(), &iv).unwrap();
+        let expected = from_hex("a5c282bad0de3774becd2e04386b589fb5057d8552fc4336");
+        assert_eq!(cbc.encrypt(PLAINTEXT).unwrap(), expected);
+        assert_eq!(cbc.decrypt(&expected).unwrap(), PLAINTEXT);
+
+        let cbc = Cbc::new(TripleDes::from_bytes(&from_hex(&KEYS[..32])).unwrap(), &iv).unwrap();
+        let expected = from_hex("acd5699dd6060a430dedd74525b78702f7f19f7c7ff9ccb6");
+        assert_eq!(cbc.encrypt_padded(&PLAINTEXT[..19], Padding::Pkcs7).unwrap(), expected);
+        assert_eq!(cbc.decrypt_padded(&expected, Padding::Pkcs7).unwrap(), &PLAINTEXT[..19]);
+    }
+
+    #[test]
+    fn test_single_key_matches_des() {
+        let tdes = TripleDes::new_single_key(&Key::from_bytes(KEY));
+        let ecb = Ecb::new(tdes);
+        let block = *b"Now is t";
+        assert_eq!(ecb.encrypt(&block).unwrap(), Ecb::new(des()).encrypt(&block).unwrap());
+    }
+
+    #[test]
+    fn test_degenerate_keys_rejected() {
+        let k1 = Key::from_bytes(KEY);
+        let k2 = Key::from_bytes([0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef, 0x01]);
+        // Difiere de k1 solo en los bits de paridad.
+        let k1_parity = Key::from_bytes([0x00, 0x22, 0x44, 0x66, 0x88, 0xaa, 0xcc, 0xee]);
+
+        assert!(matches!(TripleDes::new_two_key(&k1, &k1), Err(TripleDesError::DegenerateKeys)));
+        assert!(matches!(TripleDes::new_two_key(&k1, &k1_parity), Err(TripleDesError::DegenerateKeys)));
+        assert!(matches!(TripleDes::new_three_key(&k1, &k1, &k2), Err(TripleDesError::DegenerateKeys)));
+        assert!(matches!(TripleDes::new_three_key(&k1, &k2, &k2), Err(TripleDesError::DegenerateKeys)));
+        assert!(TripleDes::new_three_key(&k1, &k2, &k1).is_ok());
+    }
+
+    #[test]
+    fn test_invalid_key_length() {
+        for length in [0, 7, 12, 23, 32] {
+            assert!(matches!(
+                TripleDes::from_bytes(&vec![0x55; length]),
+                Err(TripleDesError::InvalidKeyLength(l)) if l == length
+            ));
+        }
+    }
+}
@@ -1 +1,7 @@
-pub mod des;
\ No newline at end of file
+pub mod cipher;
+pub mod blowfish;
+pub mod des;
+pub mod modes;
+pub mod padding;
+
+pub use cipher::BlockCipher;
@@ -0,0 +1,113 @@
+use crate::block::modes::{check_blocks, check_iv, ModeError};
+use crate::block::padding::Padding;
+use crate::block::BlockCipher;
+
+/// Cipher block chaining: each plaintext block is XORed with the previous ciphertext block.
+pub struct Cbc<C: BlockCipher> {
+    cipher: C,
+    iv: Vec<u8>,
+}
+
+impl<C: BlockCipher> Cbc<C> {
+    pub fn new(cipher: C, iv: &[u8]) -> Result<Self, ModeError> {
+        Ok(Cbc {
+            cipher,
+            iv: check_iv::<C>(iv)?,
+        })
+    }
+
+    pub fn encrypt(&self, data: &[u8]) -> Result<Vec<u8>, ModeError> {
+        check_blocks::<C>(data)?;
+        let mut output = data.to_vec();
+        let mut previous = self.iv.clone();
+        for block in output.chunks_exact_mut(C::BLOCK_SIZE) {
+            for (byte, chained) in block.iter_mut().zip(&previous) {
+                *byte ^= chained;
+            }
+            self.cipher.encrypt_block_mut(block);
+            previous.copy_from_slice(block);
+        }
+        Ok(output)
+    }
+
+    pub fn decrypt(&self, data: &[u8]) -> Result<Vec<u8>, ModeError> {
+        check_blocks::<C>(data)?;
+        let mut output = data.to_vec();
+        let mut previous = self.iv.clone();
+        for block in output.chunks_exact_mut(C::BLOCK_SIZE) {
+            let ciphertext = block.to_vec();
+            self.cipher.decrypt_block_mut(block);
+            for (byte, chained) in block.iter_mut().zip(&previous) {
+                *byte ^= chained;
+            }
+            previous = ciphertext;
+        }
+        Ok(output)
+    }
+
+    pub fn encrypt_padded(&self, data: &[u8], padding: Padding) -> Result<Vec<u8>, ModeError> {
+        let padded = padding.pad(data, C::BLOCK_SIZE).map_err(ModeError::InvalidPadding)?;
+        self.encrypt(&padded)
+    }
+
+    pub fn decrypt_padded(&self, data: &[u8], padding: Padding) -> Result<Vec<u8>, ModeError> {
+        let plaintext = self.decrypt(data)?;
+        let unpadded = padding
+            .unpad(&plaintext, C::BLOCK_SIZE)
+            .map_err(ModeError::InvalidPadding)?;
+        Ok(unpadded.to_vec())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::block::modes::test_vectors::*;
+
+    #[test]
+    fn test_fips81_cbc() {
+        let cbc = Cbc::new(des(), &IV).unwrap();
+        let expected = from_hex("e5c7cdde872bf27c43e934008c389c0f683788499a7c05f6");
+        assert_eq!(cbc.encrypt(PLAINTEXT).unwrap(), expected);
+        assert_eq!(cbc.decrypt(&expected).unwrap(), PLAINTEXT);
+    }
+
+    #[test]
+    fn test_generic_over_cipher() {
+        use crate::block::blowfish::Blowfish;
+        let cbc = Cbc::new(Blowfish::new(b"blowfish key").unwrap(), &IV).unwrap();
+        let ciphertext = cbc.encrypt(PLAINTEXT).unwrap();
+        assert_ne!(&ciphertext[..], &PLAINTEXT[..]);
+        assert_eq!(cbc.decrypt(&ciphertext).unwrap(), PLAINTEXT);
+    }
+
+    #[test]
+    fn test_padded_messages_of_any_length() {
+        let cbc = Cbc::new(des(), &IV).unwrap();
+        for len in 0..=PLAINTEXT.len() {
+            let ciphertext = cbc.encrypt_padded(&PLAINTEXT[..len], Padding::Pkcs7).unwrap();
+            assert_eq!(ciphertext.len(), (len / 8 + 1) * 8);
+            assert_eq!(cbc.decrypt_padded(&ciphertext, Padding::Pkcs7).unwrap(), &PLAINTEXT[..len]);
+        }
+    }
+
+    #[test]
+    fn test_wrong_key_reports_bad_padding() {
+        use crate::block::des::{key::Key, Des};
+        let ciphertext = Cbc::new(des(), &IV).unwrap().encrypt_padded(b"attack at dawn", Padding::Iso7816).unwrap();
+        let other = Cbc::new(Des::new(&Key::from_bytes(*b"otherkey")), &IV).unwrap();
+        assert!(matches!(
+            other.decrypt_padded(&ciphertext, Padding::Iso7816),
+            Err(ModeError::InvalidPadding(_))
+        ));
+        assert_eq!(other.decrypt_padded(&ciphertext[..7], Padding::Iso7816), Err(ModeError::InvalidDataLength(7)));
+    }
+
+    #[test]
+    fn test_invalid_lengths() {
+        assert!(matches!(Cbc::new(des(), &IV[..4]), Err(ModeError::InvalidIvLength(4))));
+        let cbc = Cbc::new(des(), &IV).unwrap();
+        assert_eq!(cbc.encrypt(&PLAINTEXT[..9]), Err(ModeError::InvalidDataLength(9)));
+    }
+}
@@ -0,0 +1,128 @@
+use crate::block::modes::{check_iv, ModeError};
+use crate::block::BlockCipher;
+
+/// CFB segment size: how many bits of feedback each cipher call produces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Segment {
+    Bit,
+    Byte,
+    Block,
+}
+
+/// Cipher feedback: the previous ciphertext segment is shifted into the input register.
+pub struct Cfb<C: BlockCipher> {
+    cipher: C,
+    iv: Vec<u8>,
+    segment: Segment,
+}
+
+impl<C: BlockCipher> Cfb<C> {
+    pub fn new(cipher: C, iv: &[u8], segment: Segment) -> Result<Self, ModeError> {
+        Ok(Cfb {
+            cipher,
+            iv: check_iv::<C>(iv)?,
+            segment,
+        })
+    }
+
+    pub fn encrypt(&self, data: &[u8]) -> Vec<u8> {
+        self.process(data, true)
+    }
+
+    pub fn decrypt(&self, data: &[u8]) -> Vec<u8> {
+        self.process(data, false)
+    }
+
+    fn process(&self, data: &[u8], encrypting: bool) -> Vec<u8> {
+        let mut register = self.iv.clone();
+        let mut keystream = vec![0u8; C::BLOCK_SIZE];
+        let mut output = data.to_vec();
+
+        match self.segment {
+            Segment::Block => {
+                // El último segmento puede ser parcial: solo se usa la parte necesaria.
+                for chunk in output.chunks_mut(C::BLOCK_SIZE) {
+                    keystream.copy_from_slice(&register);
+                    self.cipher.encrypt_block_mut(&mut keystream);
+                    let input = chunk.to_vec();
+                    for (byte, key) in chunk.iter_mut().zip(&keystream) {
+                        *byte ^= key;
+                    }
+                    let ciphertext = if encrypting { &chunk[..] } else { &input[..] };
+                    register[..ciphertext.len()].copy_from_slice(ciphertext);
+                }
+            }
+            Segment::Byte => {
+                for byte in output.iter_mut() {
+                    keystream.copy_from_slice(&register);
+                    self.cipher.encrypt_block_mut(&mut keystream);
+                    let input = *byte;
+                    *byte ^= keystream[0];
+                    register.rotate_left(1);
+                    *register.last_mut().unwrap() = if encrypting { *byte } else { input };
+                }
+            }
+            Segment::Bit => {
+                for byte in output.iter_mut() {
+                    let input = *byte;
+                    let mut result = 0u8;
+                    for shift in (0..8).rev() {
+                        keystream.copy_from_slice(&register);
+                        self.cipher.encrypt_block_mut(&mut keystream);
+                        let bit = ((input >> shift) & 1) ^ (keystream[0] >> 7);
+                        result |= bit << shift;
+                        let feedback = if encrypting { bit } else { (input >> shift) & 1 };
+                        shift_in_bit(&mut register, feedback);
+                    }
+                    *byte = result;
+                }
+            }
+        }
+        output
+    }
+}
+
+// Desplaza todo el registro un bit a la izquierda e introduce `bit` por la derecha.
+fn shift_in_bit(register: &mut [u8], bit: u8) {
+    let mut carry = bit;
+    for byte in register.iter_mut().rev() {
+        let next_carry = *byte >> 7;
+        *byte = (*byte << 1) | carry;
+        carry = next_carry;
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::block::modes::test_vectors::*;
+
+    fn round_trip(segment: Segment, plaintext: &[u8], expected: &str) {
+        let cfb = Cfb::new(des(), &IV, segment).unwrap();
+        let ciphertext = cfb.encrypt(plaintext);
+        assert_eq!(ciphertext, from_hex(expected));
+        assert_eq!(cfb.decrypt(&ciphertext), plaintext);
+    }
+
+    #[test]
+    fn test_fips81_cfb64() {
+        round_trip(Segment::Block, PLAINTEXT, "f3096249c7f46e51a69e839b1a92f78403467133898ea622");
+        round_trip(Segment::Block, &PLAINTEXT[..19], "f3096249c7f46e51a69e839b1a92f784034671");
+    }
+
+    #[test]
+    fn test_fips81_cfb8() {
+        round_trip(Segment::Byte, PLAINTEXT, "f31fda07011462ee187f43d80a7cd9b5b0d290da6e5b9a87");
+    }
+
+    #[test]
+    fn test_cfb1() {
+        round_trip(Segment::Bit, &PLAINTEXT[..4], "cd1ec959");
+    }
+
+    #[test]
+    fn test_invalid_iv() {
+        assert!(matches!(Cfb::new(des(), &[0u8; 16], Segment::Byte), Err(ModeError::InvalidIvLength(16))));
+    }
+}
@@ -0,0 +1,78 @@
+use crate::block::modes::{check_iv, ModeError};
+use crate::block::BlockCipher;
+
+/// Counter mode: encrypts successive values of a big-endian counter block.
+pub struct Ctr<C: BlockCipher> {
+    cipher: C,
+    initial_counter: Vec<u8>,
+}
+
+impl<C: BlockCipher> Ctr<C> {
+    pub fn new(cipher: C, initial_counter: &[u8]) -> Result<Self, ModeError> {
+        Ok(Ctr {
+            cipher,
+            initial_counter: check_iv::<C>(initial_counter)?,
+        })
+    }
+
+    pub fn encrypt(&self, data: &[u8]) -> Vec<u8> {
+        self.apply_keystream(data)
+    }
+
+    pub fn decrypt(&self, data: &[u8]) -> Vec<u8> {
+        self.apply_keystream(data)
+    }
+
+    fn apply_keystream(&self, data: &[u8]) -> Vec<u8> {
+        let mut counter = self.initial_counter.clone();
+        let mut keystream = vec![0u8; C::BLOCK_SIZE];
+        let mut output = data.to_vec();
+        for chunk in output.chunks_mut(C::BLOCK_SIZE) {
+            keystream.copy_from_slice(&counter);
+            self.cipher.encrypt_block_mut(&mut keystream);
+            for (byte, key) in chunk.iter_mut().zip(&keystream) {
+                *byte ^= key;
+            }
+            increment(&mut counter);
+        }
+        output
+    }
+}
+
+// Suma uno al bloque completo como entero big-endian, con desbordamiento a cero.
+fn increment(counter: &mut [u8]) {
+    for byte in counter.iter_mut().rev() {
+        *byte = byte.wrapping_add(1);
+        if *byte != 0 {
+            break;
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::block::modes::test_vectors::*;
+
+    #[test]
+    fn test_des_ctr() {
+        let ctr = Ctr::new(des(), &IV).unwrap();
+        let expected = from_hex("f3096249c7f46e51163a8ca0ffc94c27fa2f80");
+        assert_eq!(ctr.encrypt(&PLAINTEXT[..19]), expected);
+        assert_eq!(ctr.decrypt(&expected), &PLAINTEXT[..19]);
+    }
+
+    #[test]
+    fn test_counter_carries_across_bytes() {
+        let mut counter = [0xaa, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff];
+        let ctr = Ctr::new(des(), &counter).unwrap();
+        assert_eq!(ctr.encrypt(&[0u8; 16]), from_hex("e3cd09db47eb69e88c939366b1d94343"));
+
+        increment(&mut counter);
+        assert_eq!(counter, [0xab, 0, 0, 0, 0, 0, 0, 0]);
+        let mut wrapped = [0xff; 8];
+        increment(&mut wrapped);
+        assert_eq!(wrapped, [0; 8]);
+    }
+}
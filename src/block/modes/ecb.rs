@@ -0,0 +1,75 @@
+use crate::block::modes::{check_blocks, ModeError};
+use crate::block::padding::Padding;
+use crate::block::BlockCipher;
+
+/// Electronic codebook: every block is encrypted on its own, so equal blocks leak.
+pub struct Ecb<C: BlockCipher> {
+    cipher: C,
+}
+
+impl<C: BlockCipher> Ecb<C> {
+    pub fn new(cipher: C) -> Self {
+        Ecb { cipher }
+    }
+
+    pub fn encrypt(&self, data: &[u8]) -> Result<Vec<u8>, ModeError> {
+        check_blocks::<C>(data)?;
+        let mut output = data.to_vec();
+        for block in output.chunks_exact_mut(C::BLOCK_SIZE) {
+            self.cipher.encrypt_block_mut(block);
+        }
+        Ok(output)
+    }
+
+    pub fn decrypt(&self, data: &[u8]) -> Result<Vec<u8>, ModeError> {
+        check_blocks::<C>(data)?;
+        let mut output = data.to_vec();
+        for block in output.chunks_exact_mut(C::BLOCK_SIZE) {
+            self.cipher.decrypt_block_mut(block);
+        }
+        Ok(output)
+    }
+
+    pub fn encrypt_padded(&self, data: &[u8], padding: Padding) -> Result<Vec<u8>, ModeError> {
+        let padded = padding.pad(data, C::BLOCK_SIZE).map_err(ModeError::InvalidPadding)?;
+        self.encrypt(&padded)
+    }
+
+    pub fn decrypt_padded(&self, data: &[u8], padding: Padding) -> Result<Vec<u8>, ModeError> {
+        let plaintext = self.decrypt(data)?;
+        let unpadded = padding
+            .unpad(&plaintext, C::BLOCK_SIZE)
+            .map_err(ModeError::InvalidPadding)?;
+        Ok(unpadded.to_vec())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::block::modes::test_vectors::*;
+
+    #[test]
+    fn test_fips81_ecb() {
+        let ecb = Ecb::new(des());
+        let expected = from_hex("3fa40e8a984d48156a271787ab8883f9893d51ec4b563b53");
+        assert_eq!(ecb.encrypt(PLAINTEXT).unwrap(), expected);
+        assert_eq!(ecb.decrypt(&expected).unwrap(), PLAINTEXT);
+    }
+
+    #[test]
+    fn test_padded_round_trip() {
+        let ecb = Ecb::new(des());
+        let ciphertext = ecb.encrypt_padded(&PLAINTEXT[..13], Padding::AnsiX923).unwrap();
+        assert_eq!(ciphertext[..8], from_hex("3fa40e8a984d4815")[..]);
+        assert_eq!(ecb.decrypt_padded(&ciphertext, Padding::AnsiX923).unwrap(), &PLAINTEXT[..13]);
+    }
+
+    #[test]
+    fn test_partial_block_rejected() {
+        let ecb = Ecb::new(des());
+        assert_eq!(ecb.encrypt(&PLAINTEXT[..20]), Err(ModeError::InvalidDataLength(20)));
+        assert_eq!(ecb.decrypt(&[0u8; 7]), Err(ModeError::InvalidDataLength(7)));
+    }
+}
@@ -0,0 +1,59 @@
+use crate::block::padding::UnpadError;
+use crate::block::BlockCipher;
+
+pub mod cbc;
+pub mod cfb;
+pub mod ctr;
+pub mod ecb;
+pub mod ofb;
+
+pub use cbc::Cbc;
+pub use cfb::{Cfb, Segment};
+pub use ctr::Ctr;
+pub use ecb::Ecb;
+pub use ofb::Ofb;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ModeError {
+    InvalidIvLength(usize),
+    InvalidDataLength(usize),
+    InvalidPadding(UnpadError),
+}
+
+pub(crate) fn check_iv<C: BlockCipher>(iv: &[u8]) -> Result<Vec<u8>, ModeError> {
+    if iv.len() != C::BLOCK_SIZE {
+        return Err(ModeError::InvalidIvLength(iv.len()));
+    }
+    Ok(iv.to_vec())
+}
+
+// ECB y CBC solo operan sobre bloques completos; el relleno se aplica antes.
+pub(crate) fn check_blocks<C: BlockCipher>(data: &[u8]) -> Result<(), ModeError> {
+    if !data.len().is_multiple_of(C::BLOCK_SIZE) {
+        return Err(ModeError::InvalidDataLength(data.len()));
+    }
+    Ok(())
+}
+
+
+#[cfg(test)]
+pub(crate) mod test_vectors {
+    use crate::block::des::key::Key;
+    use crate::block::des::Des;
+
+    // Ejemplos de FIPS 81 (modos de operación de DES).
+    pub const KEY: [u8; 8] = [0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef];
+    pub const IV: [u8; 8] = [0x12, 0x34, 0x56, 0x78, 0x90, 0xab, 0xcd, 0xef];
+    pub const PLAINTEXT: &[u8; 24] = b"Now is the time for all ";
+
+    pub fn des() -> Des {
+        Des::new(&Key::from_bytes(KEY))
+    }
+
+    pub fn from_hex(hex: &str) -> Vec<u8> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect()
+    }
+}
@@ -0,0 +1,53 @@
+use crate::block::modes::{check_iv, ModeError};
+use crate::block::BlockCipher;
+
+/// Output feedback: the IV is encrypted repeatedly to build a keystream.
+pub struct Ofb<C: BlockCipher> {
+    cipher: C,
+    iv: Vec<u8>,
+}
+
+impl<C: BlockCipher> Ofb<C> {
+    pub fn new(cipher: C, iv: &[u8]) -> Result<Self, ModeError> {
+        Ok(Ofb {
+            cipher,
+            iv: check_iv::<C>(iv)?,
+        })
+    }
+
+    pub fn encrypt(&self, data: &[u8]) -> Vec<u8> {
+        self.apply_keystream(data)
+    }
+
+    pub fn decrypt(&self, data: &[u8]) -> Vec<u8> {
+        self.apply_keystream(data)
+    }
+
+    fn apply_keystream(&self, data: &[u8]) -> Vec<u8> {
+        let mut register = self.iv.clone();
+        let mut output = data.to_vec();
+        for chunk in output.chunks_mut(C::BLOCK_SIZE) {
+            self.cipher.encrypt_block_mut(&mut register);
+            for (byte, key) in chunk.iter_mut().zip(&register) {
+                *byte ^= key;
+            }
+        }
+        output
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::block::modes::test_vectors::*;
+
+    #[test]
+    fn test_fips81_ofb() {
+        let ofb = Ofb::new(des(), &IV).unwrap();
+        let expected = from_hex("f3096249c7f46e5135f24a242eeb3d3f3d6d5be3255af8c3");
+        assert_eq!(ofb.encrypt(PLAINTEXT), expected);
+        assert_eq!(ofb.decrypt(&expected), PLAINTEXT);
+        assert_eq!(ofb.encrypt(&PLAINTEXT[..11]), expected[..11]);
+    }
+}
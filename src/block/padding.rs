@@ -0,0 +1,176 @@
+use rand::RngCore;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Padding {
+    // n bytes con valor n.
+    Pkcs7,
+    // Ceros y el número de bytes añadidos al final.
+    AnsiX923,
+    // 0x80 seguido de ceros.
+    Iso7816,
+    // Bytes aleatorios y el número de bytes añadidos al final.
+    Iso10126,
+    // Ceros solo si hacen falta (un bloque entero si el mensaje está vacío);
+    // ambiguo si el mensaje termina en cero.
+    Zero,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum UnpadError {
+    InvalidBlockSize(usize),
+    InvalidDataLength(usize),
+    InvalidPaddingLength(usize),
+    InvalidPaddingByte(usize),
+    MissingMarker,
+}
+
+impl Padding {
+    pub fn pad(&self, data: &[u8], block_size: usize) -> Result<Vec<u8>, UnpadError> {
+        check_block_size(block_size)?;
+
+        let missing = block_size - data.len() % block_size;
+        let mut output = data.to_vec();
+        match self {
+            Padding::Pkcs7 => output.resize(data.len() + missing, missing as u8),
+            Padding::AnsiX923 => {
+                output.resize(data.len() + missing - 1, 0x00);
+                output.push(missing as u8);
+            }
+            Padding::Iso7816 => {
+                output.push(0x80);
+                output.resize(data.len() + missing, 0x00);
+            }
+            Padding::Iso10126 => {
+                let mut filler = vec![0u8; missing - 1];
+                rand::thread_rng().fill_bytes(&mut filler);
+                output.extend_from_slice(&filler);
+                output.push(missing as u8);
+            }
+            Padding::Zero => {
+                if missing != block_size || data.is_empty() {
+                    output.resize(data.len() + missing, 0x00);
+                }
+            }
+        }
+        Ok(output)
+    }
+
+    pub fn unpad<'a>(&self, data: &'a [u8], block_size: usize) -> Result<&'a [u8], UnpadError> {
+        check_block_size(block_size)?;
+        if data.is_empty() || !data.len().is_multiple_of(block_size) {
+            return Err(UnpadError::InvalidDataLength(data.len()));
+        }
+
+        match self {
+            Padding::Pkcs7 | Padding::AnsiX923 | Padding::Iso10126 => {
+                let count = data[data.len() - 1] as usize;
+                if count == 0 || count > block_size {
+                    return Err(UnpadError::InvalidPaddingLength(count));
+                }
+                let start = data.len() - count;
+                let expected = match self {
+                    Padding::Pkcs7 => Some(count as u8),
+                    Padding::AnsiX923 => Some(0x00),
+                    _ => None,
+                };
+                if let Some(expected) = expected {
+                    for (index, &byte) in data.iter().enumerate().take(data.len() - 1).skip(start) {
+                        if byte != expected {
+                            return Err(UnpadError::InvalidPaddingByte(index));
+                        }
+                    }
+                }
+                Ok(&data[..start])
+            }
+            Padding::Iso7816 => {
+                // El marcador tiene que estar dentro del último bloque.
+                for index in (data.len() - block_size..data.len()).rev() {
+                    match data[index] {
+                        0x00 => continue,
+                        0x80 => return Ok(&data[..index]),
+                        _ => return Err(UnpadError::InvalidPaddingByte(index)),
+                    }
+                }
+                Err(UnpadError::MissingMarker)
+            }
+            Padding::Zero => {
+                let end = data.iter().rposition(|&byte| byte != 0).map_or(0, |index| index + 1);
+                Ok(&data[..end.max(data.len() - block_size)])
+            }
+        }
+    }
+}
+
+// El número de bytes añadidos tiene que caber en un byte.
+fn check_block_size(block_size: usize) -> Result<(), UnpadError> {
+    if !(1..=255).contains(&block_size) {
+        return Err(UnpadError::InvalidBlockSize(block_size));
+    }
+    Ok(())
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL: [Padding; 5] = [Padding::Pkcs7, Padding::AnsiX923, Padding::Iso7816, Padding::Iso10126, Padding::Zero];
+
+    #[test]
+    fn test_padded_bytes() {
+        let data = b"abcde";
+        assert_eq!(Padding::Pkcs7.pad(data, 8).unwrap(), b"abcde\x03\x03\x03");
+        assert_eq!(Padding::AnsiX923.pad(data, 8).unwrap(), b"abcde\x00\x00\x03");
+        assert_eq!(Padding::Iso7816.pad(data, 8).unwrap(), b"abcde\x80\x00\x00");
+        assert_eq!(Padding::Zero.pad(data, 8).unwrap(), b"abcde\x00\x00\x00");
+
+        let random = Padding::Iso10126.pad(data, 8).unwrap();
+        assert_eq!(random.len(), 8);
+        assert_eq!(random[7], 3);
+    }
+
+    #[test]
+    fn test_full_block_added_when_aligned() {
+        let data = [0x41u8; 8];
+        assert_eq!(Padding::Pkcs7.pad(&data, 8).unwrap()[8..], [0x08; 8]);
+        assert_eq!(Padding::AnsiX923.pad(&data, 8).unwrap()[8..], [0, 0, 0, 0, 0, 0, 0, 8]);
+        assert_eq!(Padding::Iso7816.pad(&data, 8).unwrap()[8..], [0x80, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(Padding::Iso10126.pad(&data, 8).unwrap().len(), 16);
+        assert_eq!(Padding::Zero.pad(&data, 8).unwrap(), data);
+        assert_eq!(Padding::Zero.pad(&[], 8).unwrap(), [0u8; 8]);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for padding in ALL {
+            for len in 0..20 {
+                let data: Vec<u8> = (1..=len as u8).collect();
+                let padded = padding.pad(&data, 8).unwrap();
+                assert_eq!(padded.len() % 8, 0);
+                assert_eq!(padding.unpad(&padded, 8), Ok(&data[..]), "{:?} with {} bytes", padding, len);
+            }
+        }
+    }
+
+    #[test]
+    fn test_malformed_padding() {
+        assert_eq!(Padding::Pkcs7.unpad(b"abcde\x03\x02\x03", 8), Err(UnpadError::InvalidPaddingByte(6)));
+        assert_eq!(Padding::Pkcs7.unpad(b"abcdefg\x00", 8), Err(UnpadError::InvalidPaddingLength(0)));
+        assert_eq!(Padding::Pkcs7.unpad(b"abcdefg\x09", 8), Err(UnpadError::InvalidPaddingLength(9)));
+        assert_eq!(Padding::AnsiX923.unpad(b"abcde\x00\x01\x03", 8), Err(UnpadError::InvalidPaddingByte(6)));
+        assert_eq!(Padding::Iso10126.unpad(b"abcdefg\x10", 8), Err(UnpadError::InvalidPaddingLength(16)));
+        assert_eq!(Padding::Iso7816.unpad(b"abc\x80de\x00\x00", 8), Err(UnpadError::InvalidPaddingByte(5)));
+        assert_eq!(Padding::Iso7816.unpad(&[0u8; 16], 8), Err(UnpadError::MissingMarker));
+    }
+
+    #[test]
+    fn test_invalid_lengths() {
+        for padding in ALL {
+            assert_eq!(padding.unpad(&[], 8), Err(UnpadError::InvalidDataLength(0)));
+            assert_eq!(padding.unpad(&[1, 2, 3], 8), Err(UnpadError::InvalidDataLength(3)));
+            assert_eq!(padding.pad(b"abc", 0), Err(UnpadError::InvalidBlockSize(0)));
+            assert_eq!(padding.pad(b"abc", 256), Err(UnpadError::InvalidBlockSize(256)));
+            assert_eq!(padding.unpad(&[1; 8], 0), Err(UnpadError::InvalidBlockSize(0)));
+        }
+    }
+}
@@ -0,0 +1,277 @@
+use crate::hash::blake2::{ParamError, SIGMA};
+use crate::hash::{Digest, Output};
+
+const IV: [u64; 8] = [
+    0x6a09e667f3bcc908,
+    0xbb67ae8584caa73b,
+    0x3c6ef372fe94f82b,
+    0xa54ff53a5f1d36f1,
+    0x510e527fade682d1,
+    0x9b05688c2b3e6c1f,
+    0x1f83d9abfb41bd6b,
+    0x5be0cd19137e2179,
+];
+
+pub const MAX_DIGEST_LEN: usize = 64;
+pub const MAX_KEY_LEN: usize = 64;
+pub const SALT_LEN: usize = 16;
+pub const PERSONAL_LEN: usize = 16;
+
+#[derive(Clone)]
+pub struct Blake2b {
+    h: [u64; 8],
+    initial: [u64; 8],
+    key: Vec<u8>,
+    counter: u128,
+    buffer: [u8; 128],
+    buffer_len: usize,
+    digest_len: usize,
+}
+
+impl Blake2b {
+    pub fn new(digest_len: usize) -> Result<Self, ParamError> {
+        Blake2b::with_params(digest_len, &[], &[], &[])
+    }
+
+    pub fn new_keyed(key: &[u8], digest_len: usize) -> Result<Self, ParamError> {
+        Blake2b::with_params(digest_len, key, &[], &[])
+    }
+
+    // La sal y la personalización más cortas que su campo se rellenan con ceros.
+    pub fn with_params(digest_len: usize, key: &[u8], salt: &[u8], personal: &[u8]) -> Result<Self, ParamError> {
+        if digest_len == 0 || digest_len > MAX_DIGEST_LEN {
+            return Err(ParamError::InvalidDigestLength(digest_len));
+        }
+        if key.len() > MAX_KEY_LEN {
+            return Err(ParamError::InvalidKeyLength(key.len()));
+        }
+        if salt.len() > SALT_LEN {
+            return Err(ParamError::InvalidSaltLength(salt.len()));
+        }
+        if personal.len() > PERSONAL_LEN {
+            return Err(ParamError::InvalidPersonalLength(personal.len()));
+        }
+
+        let mut initial = IV;
+        initial[0] ^= 0x01010000 ^ ((key.len() as u64) << 8) ^ digest_len as u64;
+
+        let mut salt_block = [0u8; SALT_LEN];
+        salt_block[..salt.len()].copy_from_slice(salt);
+        let mut personal_block = [0u8; PERSONAL_LEN];
+        personal_block[..personal.len()].copy_from_slice(personal);
+        for i in 0..2 {
+            initial[4 + i] ^= u64::from_le_bytes(salt_block[i * 8..i * 8 + 8].try_into().unwrap());
+            initial[6 + i] ^= u64::from_le_bytes(personal_block[i * 8..i * 8 + 8].try_into().unwrap());
+        }
+
+        let mut blake2b = Blake2b {
+            h: initial,
+            initial,
+            key: key.to_vec(),
+            counter: 0,
+            buffer: [0u8; 128],
+            buffer_len: 0,
+            digest_len,
+        };
+        blake2b.absorb_key();
+        Ok(blake2b)
+    }
+
+    pub fn digest_len(&self) -> usize {
+        self.digest_len
+    }
+
+    pub fn update(&mut self, mut data: &[u8]) {
+        while !data.is_empty() {
+            // El último bloque se guarda hasta finalizar porque lleva la bandera final.
+            if self.buffer_len == 128 {
+                self.counter = self.counter.wrapping_add(128);
+                let block = self.buffer;
+                self.compress(&block, false);
+                self.buffer_len = 0;
+            }
+            let take = (128 - self.buffer_len).min(data.len());
+            self.buffer[self.buffer_len..self.buffer_len + take].copy_from_slice(&data[..take]);
+            self.buffer_len += take;
+            data = &data[take..];
+        }
+    }
+
+    pub fn finalize_variable(mut self) -> Vec<u8> {
+        self.counter = self.counter.wrapping_add(self.buffer_len as u128);
+        self.buffer[self.buffer_len..].fill(0);
+        let block = self.buffer;
+        self.compress(&block, true);
+
+        self.h
+            .iter()
+            .flat_map(|word| word.to_le_bytes())
+            .take(self.digest_len)
+            .collect()
+    }
+
+    pub fn reset(&mut self) {
+        self.h = self.initial;
+        self.counter = 0;
+        self.buffer_len = 0;
+        self.absorb_key();
+    }
+
+    fn absorb_key(&mut self) {
+        if !self.key.is_empty() {
+            let key = self.key.clone();
+            self.update(&key);
+            self.buffer[self.buffer_len..].fill(0);
+            self.buffer_len = 128;
+        }
+    }
+
+    fn compress(&mut self, block: &[u8; 128], last: bool) {
+        let mut m = [0u64; 16];
+        for (i, word) in m.iter_mut().enumerate() {
+            *word = u64::from_le_bytes(block[i * 8..i * 8 + 8].try_into().unwrap());
+        }
+
+        let mut v = [0u64; 16];
+        v[..8].copy_from_slice(&self.h);
+        v[8..].copy_from_slice(&IV);
+        v[12] ^= self.counter as u64;
+        v[13] ^= (self.counter >> 64) as u64;
+        if last {
+            v[14] = !v[14];
+        }
+
+        for round in 0..12 {
+            let s = &SIGMA[round % 10];
+            Blake2b::mix(&mut v, 0, 4, 8, 12, m[s[0]], m[s[1]]);
+            Blake2b::mix(&mut v, 1, 5, 9, 13, m[s[2]], m[s[3]]);
+            Blake2b::mix(&mut v, 2, 6, 10, 14, m[s[4]], m[s[5]]);
+            Blake2b::mix(&mut v, 3, 7, 11, 15, m[s[6]], m[s[7]]);
+            Blake2b::mix(&mut v, 0, 5, 10, 15, m[s[8]], m[s[9]]);
+            Blake2b::mix(&mut v, 1, 6, 11, 12, m[s[10]], m[s[11]]);
+            Blake2b::mix(&mut v, 2, 7, 8, 13, m[s[12]], m[s[13]]);
+            Blake2b::mix(&mut v, 3, 4, 9, 14, m[s[14]], m[s[15]]);
+        }
+
+        for i in 0..8 {
+            self.h[i] ^= v[i] ^ v[i + 8];
+        }
+    }
+
+    fn mix(v: &mut [u64; 16], a: usize, b: usize, c: usize, d: usize, x: u64, y: u64) {
+        v[a] = v[a].wrapping_add(v[b]).wrapping_add(x);
+        v[d] = (v[d] ^ v[a]).rotate_right(32);
+        v[c] = v[c].wrapping_add(v[d]);
+        v[b] = (v[b] ^ v[c]).rotate_right(24);
+        v[a] = v[a].wrapping_add(v[b]).wrapping_add(y);
+        v[d] = (v[d] ^ v[a]).rotate_right(16);
+        v[c] = v[c].wrapping_add(v[d]);
+        v[b] = (v[b] ^ v[c]).rotate_right(63);
+    }
+}
+
+// BLAKE2b sin clave con salida completa, para usarlo donde se espera un `Digest`.
+#[derive(Clone)]
+pub struct Blake2b512 {
+    inner: Blake2b,
+}
+
+impl Digest for Blake2b512 {
+    type Output = Output<64>;
+
+    const OUTPUT_SIZE: usize = 64;
+    const BLOCK_SIZE: usize = 128;
+
+    fn new() -> Self {
+        Blake2b512 {
+            inner: Blake2b::new(64).unwrap(),
+        }
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        self.inner.update(data);
+    }
+
+    fn finalize(self) -> Output<64> {
+        let mut digest = [0u8; 64];
+        digest.copy_from_slice(&self.inner.finalize_variable());
+        Output::from(digest)
+    }
+
+    fn reset(&mut self) {
+        self.inner.reset();
+    }
+}
+
+impl Default for Blake2b512 {
+    fn default() -> Self {
+        Blake2b512::new()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+    }
+
+    #[test]
+    fn test_rfc7693_abc() {
+        assert_eq!(
+            Blake2b512::digest(b"abc").to_hex(),
+            "ba80a53f981c4d0d6a2797b69f12f6e94c212f14685ac4b74b12bb6fdbffa2d17d87c5392aab792dc252d5de4533cc9518d38aa8dbf1925ab92386edd4009923"
+        );
+        assert_eq!(
+            Blake2b512::digest(b"").to_hex(),
+            "786a02f742015903c6c6fd852552d272912f4740e15847618a86e217f71f5419d25e1031afee585313896444934eb04b903a685b1448b755d56f701afe9be2ce"
+        );
+    }
+
+    #[test]
+    fn test_keyed() {
+        let key: Vec<u8> = (0..64).collect();
+        let message: Vec<u8> = (0..255).collect();
+        let mut blake2b = Blake2b::new_keyed(&key, 64).unwrap();
+        blake2b.update(&message);
+        assert_eq!(
+            hex(&blake2b.finalize_variable()),
+            "142709d62e28fcccd0af97fad0f8465b971e82201dc51070faa0372aa43e92484be1c1e73ba10906d5d1853db6a4106e0a7bf9800d373d6dee2d46d62ef2a461"
+        );
+
+        let blake2b = Blake2b::new_keyed(b"key", 64).unwrap();
+        assert_eq!(
+            hex(&blake2b.finalize_variable()),
+            "5b3cfd8f422b490b764b55eceb330b500c79cbefa9a928ad00202b8b3c5dd778a81122570434a2e3b8bfd028d105dfefd0a9576e88ed66de742ca9fbb5f8d2b6"
+        );
+    }
+
+    #[test]
+    fn test_salt_personal_and_length() {
+        let mut blake2b = Blake2b::with_params(32, b"secret", b"0123456789abcdef", b"rustcrypto-tests").unwrap();
+        blake2b.update(b"hello");
+        assert_eq!(hex(&blake2b.finalize_variable()), "c062d891df44dbd635b7085df74344e43757602f521d537f338225440fc62ffc");
+
+        let blake2b = Blake2b::new(20).unwrap();
+        assert_eq!(hex(&blake2b.finalize_variable()), "3345524abf6bbe1809449224b5972c41790b6cf2");
+    }
+
+    #[test]
+    fn test_reset_keeps_key() {
+        let mut blake2b = Blake2b::new_keyed(b"key", 64).unwrap();
+        blake2b.update(b"garbage");
+        blake2b.reset();
+        assert_eq!(hex(&blake2b.finalize_variable()), hex(&Blake2b::new_keyed(b"key", 64).unwrap().finalize_variable()));
+    }
+
+    #[test]
+    fn test_invalid_params() {
+        assert_eq!(Blake2b::new(0).err(), Some(ParamError::InvalidDigestLength(0)));
+        assert_eq!(Blake2b::new(65).err(), Some(ParamError::InvalidDigestLength(65)));
+        assert_eq!(Blake2b::new_keyed(&[0u8; 65], 64).err(), Some(ParamError::InvalidKeyLength(65)));
+        assert_eq!(Blake2b::with_params(64, &[], &[0u8; 17], &[]).err(), Some(ParamError::InvalidSaltLength(17)));
+        assert_eq!(Blake2b::with_params(64, &[], &[], &[0u8; 17]).err(), Some(ParamError::InvalidPersonalLength(17)));
+    }
+}
@@ -0,0 +1,248 @@
+use crate::hash::blake2::{ParamError, SIGMA};
+use crate::hash::{Digest, Output};
+
+const IV: [u32; 8] = [
+    0x6a09e667,
+    0xbb67ae85,
+    0x3c6ef372,
+    0xa54ff53a,
+    0x510e527f,
+    0x9b05688c,
+    0x1f83d9ab,
+    0x5be0cd19,
+];
+
+pub const MAX_DIGEST_LEN: usize = 32;
+pub const MAX_KEY_LEN: usize = 32;
+pub const SALT_LEN: usize = 8;
+pub const PERSONAL_LEN: usize = 8;
+
+#[derive(Clone)]
+pub struct Blake2s {
+    h: [u32; 8],
+    initial: [u32; 8],
+    key: Vec<u8>,
+    counter: u64,
+    buffer: [u8; 64],
+    buffer_len: usize,
+    digest_len: usize,
+}
+
+impl Blake2s {
+    pub fn new(digest_len: usize) -> Result<Self, ParamError> {
+        Blake2s::with_params(digest_len, &[], &[], &[])
+    }
+
+    pub fn new_keyed(key: &[u8], digest_len: usize) -> Result<Self, ParamError> {
+        Blake2s::with_params(digest_len, key, &[], &[])
+    }
+
+    // La sal y la personalización más cortas que su campo se rellenan con ceros.
+    pub fn with_params(digest_len: usize, key: &[u8], salt: &[u8], personal: &[u8]) -> Result<Self, ParamError> {
+        if digest_len == 0 || digest_len > MAX_DIGEST_LEN {
+            return Err(ParamError::InvalidDigestLength(digest_len));
+        }
+        if key.len() > MAX_KEY_LEN {
+            return Err(ParamError::InvalidKeyLength(key.len()));
+        }
+        if salt.len() > SALT_LEN {
+            return Err(ParamError::InvalidSaltLength(salt.len()));
+        }
+        if personal.len() > PERSONAL_LEN {
+            return Err(ParamError::InvalidPersonalLength(personal.len()));
+        }
+
+        let mut initial = IV;
+        initial[0] ^= 0x01010000 ^ ((key.len() as u32) << 8) ^ digest_len as u32;
+
+        let mut salt_block = [0u8; SALT_LEN];
+        salt_block[..salt.len()].copy_from_slice(salt);
+        let mut personal_block = [0u8; PERSONAL_LEN];
+        personal_block[..personal.len()].copy_from_slice(personal);
+        for i in 0..2 {
+            initial[4 + i] ^= u32::from_le_bytes(salt_block[i * 4..i * 4 + 4].try_into().unwrap());
+            initial[6 + i] ^= u32::from_le_bytes(personal_block[i * 4..i * 4 + 4].try_into().unwrap());
+        }
+
+        let mut blake2s = Blake2s {
+            h: initial,
+            initial,
+            key: key.to_vec(),
+            counter: 0,
+            buffer: [0u8; 64],
+            buffer_len: 0,
+            digest_len,
+        };
+        blake2s.absorb_key();
+        Ok(blake2s)
+    }
+
+    pub fn digest_len(&self) -> usize {
+        self.digest_len
+    }
+
+    pub fn update(&mut self, mut data: &[u8]) {
+        while !data.is_empty() {
+            // El último bloque se guarda hasta finalizar porque lleva la bandera final.
+            if self.buffer_len == 64 {
+                self.counter = self.counter.wrapping_add(64);
+                let block = self.buffer;
+                self.compress(&block, false);
+                self.buffer_len = 0;
+            }
+            let take = (64 - self.buffer_len).min(data.len());
+            self.buffer[self.buffer_len..self.buffer_len + take].copy_from_slice(&data[..take]);
+            self.buffer_len += take;
+            data = &data[take..];
+        }
+    }
+
+    pub fn finalize_variable(mut self) -> Vec<u8> {
+        self.counter = self.counter.wrapping_add(self.buffer_len as u64);
+        self.buffer[self.buffer_len..].fill(0);
+        let block = self.buffer;
+        self.compress(&block, true);
+
+        self.h
+            .iter()
+            .flat_map(|word| word.to_le_bytes())
+            .take(self.digest_len)
+            .collect()
+    }
+
+    pub fn reset(&mut self) {
+        self.h = self.initial;
+        self.counter = 0;
+        self.buffer_len = 0;
+        self.absorb_key();
+    }
+
+    fn absorb_key(&mut self) {
+        if !self.key.is_empty() {
+            let key = self.key.clone();
+            self.update(&key);
+            self.buffer[self.buffer_len..].fill(0);
+            self.buffer_len = 64;
+        }
+    }
+
+    fn compress(&mut self, block: &[u8; 64], last: bool) {
+        let mut m = [0u32; 16];
+        for (i, word) in m.iter_mut().enumerate() {
+            *word = u32::from_le_bytes(block[i * 4..i * 4 + 4].try_into().unwrap());
+        }
+
+        let mut v = [0u32; 16];
+        v[..8].copy_from_slice(&self.h);
+        v[8..].copy_from_slice(&IV);
+        v[12] ^= self.counter as u32;
+        v[13] ^= (self.counter >> 32) as u32;
+        if last {
+            v[14] = !v[14];
+        }
+
+        for s in &SIGMA {
+            Blake2s::mix(&mut v, 0, 4, 8, 12, m[s[0]], m[s[1]]);
+            Blake2s::mix(&mut v, 1, 5, 9, 13, m[s[2]], m[s[3]]);
+            Blake2s::mix(&mut v, 2, 6, 10, 14, m[s[4]], m[s[5]]);
+            Blake2s::mix(&mut v, 3, 7, 11, 15, m[s[6]], m[s[7]]);
+            Blake2s::mix(&mut v, 0, 5, 10, 15, m[s[8]], m[s[9]]);
+            Blake2s::mix(&mut v, 1, 6, 11, 12, m[s[10]], m[s[11]]);
+            Blake2s::mix(&mut v, 2, 7, 8, 13, m[s[12]], m[s[13]]);
+            Blake2s::mix(&mut v, 3, 4, 9, 14, m[s[14]], m[s[15]]);
+        }
+
+        for i in 0..8 {
+            self.h[i] ^= v[i] ^ v[i + 8];
+        }
+    }
+
+    fn mix(v: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize, x: u32, y: u32) {
+        v[a] = v[a].wrapping_add(v[b]).wrapping_add(x);
+        v[d] = (v[d] ^ v[a]).rotate_right(16);
+        v[c] = v[c].wrapping_add(v[d]);
+        v[b] = (v[b] ^ v[c]).rotate_right(12);
+        v[a] = v[a].wrapping_add(v[b]).wrapping_add(y);
+        v[d] = (v[d] ^ v[a]).rotate_right(8);
+        v[c] = v[c].wrapping_add(v[d]);
+        v[b] = (v[b] ^ v[c]).rotate_right(7);
+    }
+}
+
+// BLAKE2s sin clave con salida completa, para usarlo donde se espera un `Digest`.
+#[derive(Clone)]
+pub struct Blake2s256 {
+    inner: Blake2s,
+}
+
+impl Digest for Blake2s256 {
+    type Output = Output<32>;
+
+    const OUTPUT_SIZE: usize = 32;
+    const BLOCK_SIZE: usize = 64;
+
+    fn new() -> Self {
+        Blake2s256 {
+            inner: Blake2s::new(32).unwrap(),
+        }
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        self.inner.update(data);
+    }
+
+    fn finalize(self) -> Output<32> {
+        let mut digest = [0u8; 32];
+        digest.copy_from_slice(&self.inner.finalize_variable());
+        Output::from(digest)
+    }
+
+    fn reset(&mut self) {
+        self.inner.reset();
+    }
+}
+
+impl Default for Blake2s256 {
+    fn default() -> Self {
+        Blake2s256::new()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+    }
+
+    #[test]
+    fn test_rfc7693_abc() {
+        assert_eq!(Blake2s256::digest(b"abc").to_hex(), "508c5e8c327c14e2e1a72ba34eeb452f37458b209ed63a294d999b4c86675982");
+        assert_eq!(Blake2s256::digest(b"").to_hex(), "69217a3079908094e11121d042354a7c1f55b6482ca1a51e1b250dfd1ed0eef9");
+    }
+
+    #[test]
+    fn test_keyed() {
+        let key: Vec<u8> = (0..32).collect();
+        let message: Vec<u8> = (0..255).collect();
+        let mut blake2s = Blake2s::new_keyed(&key, 32).unwrap();
+        blake2s.update(&message);
+        assert_eq!(hex(&blake2s.finalize_variable()), "3fb735061abc519dfe979e54c1ee5bfad0a9d858b3315bad34bde999efd724dd");
+    }
+
+    #[test]
+    fn test_salt_personal_and_length() {
+        let mut blake2s = Blake2s::with_params(16, b"secret", b"saltsalt", b"rcrypto!").unwrap();
+        blake2s.update(b"hello");
+        assert_eq!(hex(&blake2s.finalize_variable()), "904ee39d5fd3aca51dd276972098c4b5");
+    }
+
+    #[test]
+    fn test_invalid_params() {
+        assert_eq!(Blake2s::new(33).err(), Some(ParamError::InvalidDigestLength(33)));
+        assert_eq!(Blake2s::new_keyed(&[0u8; 33], 32).err(), Some(ParamError::InvalidKeyLength(33)));
+        assert_eq!(Blake2s::with_params(32, &[], &[0u8; 9], &[]).err(), Some(ParamError::InvalidSaltLength(9)));
+    }
+}
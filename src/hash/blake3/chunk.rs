@@ -0,0 +1,122 @@
+use crate::hash::blake3::compress::{compress, words_from_le_bytes, CHUNK_END, CHUNK_START, PARENT, ROOT};
+
+pub const BLOCK_LEN: usize = 64;
+pub const CHUNK_LEN: usize = 1024;
+
+// Entrada pendiente de una compresión: puede dar un valor de encadenamiento
+// o, con la bandera ROOT, tantos bytes de salida como se pidan.
+#[derive(Clone)]
+pub struct Node {
+    chaining_value: [u32; 8],
+    block_words: [u32; 16],
+    counter: u64,
+    block_len: u32,
+    flags: u32,
+}
+
+impl Node {
+    pub fn parent(left: [u32; 8], right: [u32; 8], key_words: [u32; 8], flags: u32) -> Self {
+        let mut block_words = [0u32; 16];
+        block_words[..8].copy_from_slice(&left);
+        block_words[8..].copy_from_slice(&right);
+        Node {
+            chaining_value: key_words,
+            block_words,
+            counter: 0,
+            block_len: BLOCK_LEN as u32,
+            flags: PARENT | flags,
+        }
+    }
+
+    pub fn chaining_value(&self) -> [u32; 8] {
+        let state = compress(&self.chaining_value, &self.block_words, self.counter, self.block_len, self.flags);
+        state[..8].try_into().unwrap()
+    }
+
+    pub fn root_block(&self, output_block_counter: u64) -> [u8; 64] {
+        let state = compress(&self.chaining_value, &self.block_words, output_block_counter, self.block_len, self.flags | ROOT);
+        let mut bytes = [0u8; 64];
+        for (chunk, word) in bytes.chunks_exact_mut(4).zip(state.iter()) {
+            chunk.copy_from_slice(&word.to_le_bytes());
+        }
+        bytes
+    }
+}
+
+#[derive(Clone)]
+pub struct ChunkState {
+    chaining_value: [u32; 8],
+    chunk_counter: u64,
+    block: [u8; BLOCK_LEN],
+    block_len: usize,
+    blocks_compressed: usize,
+    flags: u32,
+}
+
+impl ChunkState {
+    pub fn new(key_words: [u32; 8], chunk_counter: u64, flags: u32) -> Self {
+        ChunkState {
+            chaining_value: key_words,
+            chunk_counter,
+            block: [0u8; BLOCK_LEN],
+            block_len: 0,
+            blocks_compressed: 0,
+            flags,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        BLOCK_LEN * self.blocks_compressed + self.block_len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn chunk_counter(&self) -> u64 {
+        self.chunk_counter
+    }
+
+    fn start_flag(&self) -> u32 {
+        if self.blocks_compressed == 0 {
+            CHUNK_START
+        } else {
+            0
+        }
+    }
+
+    pub fn update(&mut self, mut input: &[u8]) {
+        while !input.is_empty() {
+            // El último bloque del chunk se comprime al cerrar el nodo, con CHUNK_END.
+            if self.block_len == BLOCK_LEN {
+                let block_words = words_from_le_bytes(&self.block);
+                let state = compress(
+                    &self.chaining_value,
+                    &block_words,
+                    self.chunk_counter,
+                    BLOCK_LEN as u32,
+                    self.flags | self.start_flag(),
+                );
+                self.chaining_value = state[..8].try_into().unwrap();
+                self.blocks_compressed += 1;
+                self.block = [0u8; BLOCK_LEN];
+                self.block_len = 0;
+            }
+
+            let take = (BLOCK_LEN - self.block_len).min(input.len());
+            self.block[self.block_len..self.block_len + take].copy_from_slice(&input[..take]);
+            self.block_len += take;
+            input = &input[take..];
+        }
+    }
+
+    pub fn node(&self) -> Node {
+        Node {
+            chaining_value: self.chaining_value,
+            block_words: words_from_le_bytes(&self.block),
+            counter: self.chunk_counter,
+            block_len: self.block_len as u32,
+            flags: self.flags | self.start_flag() | CHUNK_END,
+        }
+    }
+}
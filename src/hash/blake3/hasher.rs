@@ -0,0 +1,210 @@
+use std::io::{self, Read};
+
+use crate::hash::blake3::chunk::{ChunkState, Node, CHUNK_LEN};
+use crate::hash::blake3::compress::{words_from_le_bytes, DERIVE_KEY_CONTEXT, DERIVE_KEY_MATERIAL, IV, KEYED_HASH};
+use crate::hash::{Digest, Output};
+
+/// Incremental BLAKE3 over the chunk tree, merging subtrees as chunks complete.
+#[derive(Clone)]
+pub struct Hasher {
+    chunk_state: ChunkState,
+    key_words: [u32; 8],
+    cv_stack: Vec<[u32; 8]>,
+    flags: u32,
+}
+
+impl Hasher {
+    fn with_key_words(key_words: [u32; 8], flags: u32) -> Self {
+        Hasher {
+            chunk_state: ChunkState::new(key_words, 0, flags),
+            key_words,
+            cv_stack: Vec::new(),
+            flags,
+        }
+    }
+
+    pub fn new_keyed(key: &[u8; 32]) -> Self {
+        Hasher::with_key_words(words_from_le_bytes(key), KEYED_HASH)
+    }
+
+    pub fn new_derive_key(context: &str) -> Self {
+        let mut context_hasher = Hasher::with_key_words(IV, DERIVE_KEY_CONTEXT);
+        context_hasher.update(context.as_bytes());
+        let context_key = context_hasher.finalize();
+        Hasher::with_key_words(words_from_le_bytes(context_key.as_bytes()), DERIVE_KEY_MATERIAL)
+    }
+
+    // Tras `total_chunks` chunks, cada bit a cero al final marca un subárbol completo que se puede fusionar.
+    fn push_chunk_chaining_value(&mut self, mut chaining_value: [u32; 8], mut total_chunks: u64) {
+        while total_chunks & 1 == 0 {
+            let left = self.cv_stack.pop().unwrap();
+            chaining_value = Node::parent(left, chaining_value, self.key_words, self.flags).chaining_value();
+            total_chunks >>= 1;
+        }
+        self.cv_stack.push(chaining_value);
+    }
+
+    fn root_node(&self) -> Node {
+        let mut node = self.chunk_state.node();
+        for &left in self.cv_stack.iter().rev() {
+            node = Node::parent(left, node.chaining_value(), self.key_words, self.flags);
+        }
+        node
+    }
+
+    pub fn finalize_xof(&self) -> OutputReader {
+        OutputReader::new(self.root_node())
+    }
+}
+
+impl Digest for Hasher {
+    type Output = Output<32>;
+
+    const OUTPUT_SIZE: usize = 32;
+    const BLOCK_SIZE: usize = 64;
+
+    fn new() -> Self {
+        Hasher::with_key_words(IV, 0)
+    }
+
+    fn update(&mut self, mut data: &[u8]) {
+        while !data.is_empty() {
+            if self.chunk_state.len() == CHUNK_LEN {
+                let chaining_value = self.chunk_state.node().chaining_value();
+                let total_chunks = self.chunk_state.chunk_counter() + 1;
+                self.push_chunk_chaining_value(chaining_value, total_chunks);
+                self.chunk_state = ChunkState::new(self.key_words, total_chunks, self.flags);
+            }
+
+            let take = (CHUNK_LEN - self.chunk_state.len()).min(data.len());
+            self.chunk_state.update(&data[..take]);
+            data = &data[take..];
+        }
+    }
+
+    fn finalize(self) -> Output<32> {
+        let mut digest = [0u8; 32];
+        digest.copy_from_slice(&self.root_node().root_block(0)[..32]);
+        Output::from(digest)
+    }
+
+    fn reset(&mut self) {
+        self.chunk_state = ChunkState::new(self.key_words, 0, self.flags);
+        self.cv_stack.clear();
+    }
+}
+
+impl Default for Hasher {
+    fn default() -> Self {
+        Hasher::new()
+    }
+}
+
+pub struct OutputReader {
+    node: Node,
+    block_counter: u64,
+    block: [u8; 64],
+    position: usize,
+}
+
+impl OutputReader {
+    pub(crate) fn new(node: Node) -> Self {
+        OutputReader {
+            block: node.root_block(0),
+            node,
+            block_counter: 0,
+            position: 0,
+        }
+    }
+
+    pub fn squeeze(&mut self, output: &mut [u8]) {
+        for byte in output.iter_mut() {
+            if self.position == 64 {
+                self.block_counter += 1;
+                self.block = self.node.root_block(self.block_counter);
+                self.position = 0;
+            }
+            *byte = self.block[self.position];
+            self.position += 1;
+        }
+    }
+
+    pub fn read_vec(&mut self, len: usize) -> Vec<u8> {
+        let mut output = vec![0u8; len];
+        self.squeeze(&mut output);
+        output
+    }
+}
+
+impl Read for OutputReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.squeeze(buf);
+        Ok(buf.len())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: &[u8; 32] = b"whats the Elvish word for friend";
+    const CONTEXT: &str = "BLAKE3 2019-12-27 16:29:52 test vectors context";
+
+    fn input(len: usize) -> Vec<u8> {
+        (0..len).map(|i| (i % 251) as u8).collect()
+    }
+
+    fn hash_in_pieces(mut hasher: Hasher, data: &[u8], piece: usize) -> String {
+        for chunk in data.chunks(piece) {
+            hasher.update(chunk);
+        }
+        hasher.finalize().to_hex()
+    }
+
+    #[test]
+    fn test_official_vectors() {
+        let vectors = [
+            (0, "af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262"),
+            (1, "2d3adedff11b61f14c886e35afa036736dcd87a74d27b5c1510225d0f592e213"),
+            (1024, "42214739f095a406f3fc83deb889744ac00df831c10daa55189b5d121c855af7"),
+            (1025, "d00278ae47eb27b34faecf67b4fe263f82d5412916c1ffd97c8cb7fb814b8444"),
+            (2049, "5f4d72f40d7a5f82b15ca2b2e44b1de3c2ef86c426c95c1af0b6879522563030"),
+            (8193, "bab6c09cb8ce8cf459261398d2e7aef35700bf488116ceb94a36d0f5f1b7bc3b"),
+            (31744, "62b6960e1a44bcc1eb1a611a8d6235b6b4b78f32e7abc4fb4c6cdcce94895c47"),
+        ];
+        for (len, expected) in vectors {
+            assert_eq!(hash_in_pieces(Hasher::new(), &input(len), 1000), expected);
+        }
+    }
+
+    #[test]
+    fn test_keyed_and_derive_key_modes() {
+        assert_eq!(hash_in_pieces(Hasher::new_keyed(KEY), &input(0), 1), "92b2b75604ed3c761f9d6f62392c8a9227ad0ea3f09573e783f1498a4ed60d26");
+        assert_eq!(hash_in_pieces(Hasher::new_keyed(KEY), &input(8193), 77), "954a2a75420c8d6547e3ba5b98d963e6fa6491addc8c023189cc519821b4a1f5");
+        assert_eq!(hash_in_pieces(Hasher::new_derive_key(CONTEXT), &input(1), 1), "b3e2e340a117a499c6cf2398a19ee0d29cca2bb7404c73063382693bf66cb06c");
+        assert_eq!(hash_in_pieces(Hasher::new_derive_key(CONTEXT), &input(2049), 300), "2ea477c5515cc3dd606512ee72bb3e0e758cfae7232826f35fb98ca1bcbdf273");
+    }
+
+    #[test]
+    fn test_extended_output() {
+        let mut hasher = Hasher::new();
+        hasher.update(&input(1025));
+        let mut reader = hasher.finalize_xof();
+        let mut output = reader.read_vec(100);
+        output.extend(reader.read_vec(31));
+        let hex: String = output.iter().map(|byte| format!("{:02x}", byte)).collect();
+        assert_eq!(
+            hex,
+            "d00278ae47eb27b34faecf67b4fe263f82d5412916c1ffd97c8cb7fb814b8444f4c4a22b4b399155358a994e52bf255de60035742ec71bd08ac275a1b51cc6bfe332b0ef84b409108cda080e6269ed4b3e2c3f7d722aa4cdc98d16deb554e5627be8f955c98e1d5f9565a9194cad0c4285f93700062d9595adb992ae68ff12800ab67a"
+        );
+    }
+
+    #[test]
+    fn test_reset_keeps_mode() {
+        let mut hasher = Hasher::new_keyed(KEY);
+        hasher.update(&input(5000));
+        hasher.reset();
+        assert_eq!(hasher.finalize().to_hex(), "92b2b75604ed3c761f9d6f62392c8a9227ad0ea3f09573e783f1498a4ed60d26");
+    }
+}
@@ -0,0 +1,7 @@
+pub mod compress;
+pub mod chunk;
+pub mod hasher;
+pub mod tree;
+
+pub use hasher::{Hasher, OutputReader};
+pub use tree::{derive_key, hash, hash_xof, keyed_hash};
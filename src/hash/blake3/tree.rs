@@ -0,0 +1,123 @@
+use crate::hash::blake3::chunk::{ChunkState, Node, CHUNK_LEN};
+use crate::hash::blake3::compress::{words_from_le_bytes, DERIVE_KEY_CONTEXT, DERIVE_KEY_MATERIAL, IV, KEYED_HASH};
+use crate::hash::blake3::OutputReader;
+use crate::hash::Output;
+
+// Por debajo de este tamaño no compensa repartir el subárbol entre hilos.
+#[cfg(feature = "rayon")]
+const PARALLEL_MIN_LEN: usize = 16 * CHUNK_LEN;
+
+pub fn hash(data: &[u8]) -> Output<32> {
+    root_hash(subtree_node(data, IV, 0, 0))
+}
+
+pub fn keyed_hash(key: &[u8; 32], data: &[u8]) -> Output<32> {
+    root_hash(subtree_node(data, words_from_le_bytes(key), 0, KEYED_HASH))
+}
+
+pub fn derive_key(context: &str, key_material: &[u8]) -> Output<32> {
+    let context_key = root_hash(subtree_node(context.as_bytes(), IV, 0, DERIVE_KEY_CONTEXT));
+    root_hash(subtree_node(key_material, words_from_le_bytes(context_key.as_bytes()), 0, DERIVE_KEY_MATERIAL))
+}
+
+pub fn hash_xof(data: &[u8]) -> OutputReader {
+    OutputReader::new(subtree_node(data, IV, 0, 0))
+}
+
+fn root_hash(node: Node) -> Output<32> {
+    let mut digest = [0u8; 32];
+    digest.copy_from_slice(&node.root_block(0)[..32]);
+    Output::from(digest)
+}
+
+// El subárbol izquierdo se lleva la mayor potencia de dos de chunks completos
+// que deje al menos un byte para el derecho.
+fn left_len(len: usize) -> usize {
+    let full_chunks = (len - 1) / CHUNK_LEN;
+    (1usize << (usize::BITS - 1 - full_chunks.leading_zeros())) * CHUNK_LEN
+}
+
+fn subtree_node(input: &[u8], key_words: [u32; 8], chunk_counter: u64, flags: u32) -> Node {
+    if input.len() <= CHUNK_LEN {
+        let mut chunk_state = ChunkState::new(key_words, chunk_counter, flags);
+        chunk_state.update(input);
+        return chunk_state.node();
+    }
+
+    let (left, right) = input.split_at(left_len(input.len()));
+    let right_counter = chunk_counter + (left.len() / CHUNK_LEN) as u64;
+    let (left_cv, right_cv) = join(
+        input.len(),
+        || subtree_node(left, key_words, chunk_counter, flags).chaining_value(),
+        || subtree_node(right, key_words, right_counter, flags).chaining_value(),
+    );
+    Node::parent(left_cv, right_cv, key_words, flags)
+}
+
+#[cfg(feature = "rayon")]
+fn join<A, B>(len: usize, left: A, right: B) -> ([u32; 8], [u32; 8])
+where
+    A: FnOnce() -> [u32; 8] + Send,
+    B: FnOnce() -> [u32; 8] + Send,
+{
+    if len >= PARALLEL_MIN_LEN {
+        rayon::join(left, right)
+    } else {
+        (left(), right())
+    }
+}
+
+#[cfg(not(feature = "rayon"))]
+fn join<A, B>(_len: usize, left: A, right: B) -> ([u32; 8], [u32; 8])
+where
+    A: FnOnce() -> [u32; 8],
+    B: FnOnce() -> [u32; 8],
+{
+    (left(), right())
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hash::blake3::Hasher;
+    use crate::hash::Digest;
+
+    fn input(len: usize) -> Vec<u8> {
+        (0..len).map(|i| (i % 251) as u8).collect()
+    }
+
+    #[test]
+    fn test_left_len() {
+        assert_eq!(left_len(1025), 1024);
+        assert_eq!(left_len(2048), 1024);
+        assert_eq!(left_len(2049), 2048);
+        assert_eq!(left_len(4096), 2048);
+        assert_eq!(left_len(4097), 4096);
+    }
+
+    #[test]
+    fn test_one_shot_vectors() {
+        assert_eq!(hash(&input(0)).to_hex(), "af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262");
+        assert_eq!(hash(&input(31744)).to_hex(), "62b6960e1a44bcc1eb1a611a8d6235b6b4b78f32e7abc4fb4c6cdcce94895c47");
+        assert_eq!(
+            keyed_hash(b"whats the Elvish word for friend", &input(1025)).to_hex(),
+            "357dc55de0c7e382c900fd6e320acc04146be01db6a8ce7210b7189bd664ea69"
+        );
+        assert_eq!(
+            derive_key("BLAKE3 2019-12-27 16:29:52 test vectors context", &input(31744)).to_hex(),
+            "39772aef80e0ebe60596361e45b061e8f417429d529171b6764468c22928e28e"
+        );
+    }
+
+    #[test]
+    fn test_tree_matches_incremental_hasher() {
+        for len in [0, 1, 1023, 1024, 1025, 3072, 3073, 5121, 16384, 40000, 102400] {
+            let data = input(len);
+            let mut hasher = Hasher::new();
+            hasher.update(&data);
+            assert_eq!(hash_xof(&data).read_vec(200), hasher.finalize_xof().read_vec(200));
+            assert_eq!(hash(&data), hasher.finalize());
+        }
+    }
+}
@@ -0,0 +1,60 @@
+pub trait Digest: Sized + Clone {
+    type Output: AsRef<[u8]>;
+
+    const OUTPUT_SIZE: usize;
+    const BLOCK_SIZE: usize;
+
+    fn new() -> Self;
+
+    fn update(&mut self, data: &[u8]);
+
+    fn finalize(self) -> Self::Output;
+
+    fn reset(&mut self);
+
+    fn digest(data: &[u8]) -> Self::Output {
+        let mut hasher = Self::new();
+        hasher.update(data);
+        hasher.finalize()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hash::{md5, sha256};
+
+    fn digest_in_halves<H: Digest>(data: &[u8]) -> H::Output {
+        let mut hasher = H::new();
+        let (first, second) = data.split_at(data.len() / 2);
+        hasher.update(first);
+        hasher.update(second);
+        hasher.finalize()
+    }
+
+    #[test]
+    fn test_generic_over_hash_function() {
+        let data = b"The quick brown fox jumps over the lazy dog";
+        assert_eq!(digest_in_halves::<md5::Hasher>(data), md5::Hasher::digest(data));
+        assert_eq!(digest_in_halves::<sha256::Hasher>(data), sha256::Hasher::digest(data));
+        assert_eq!(md5::Hasher::digest(data).to_hex(), "9e107d9d372bb6826bd81d3542a419d6");
+    }
+
+    #[test]
+    fn test_sizes() {
+        assert_eq!(md5::Hasher::OUTPUT_SIZE, 16);
+        assert_eq!(md5::Hasher::BLOCK_SIZE, 64);
+        assert_eq!(sha256::Hasher::OUTPUT_SIZE, 32);
+        assert_eq!(sha256::Hasher::BLOCK_SIZE, 64);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut hasher = sha256::Hasher::new();
+        hasher.update(b"garbage");
+        hasher.reset();
+        hasher.update(b"abc");
+        assert_eq!(hasher.finalize(), sha256::Hasher::digest(b"abc"));
+    }
+}
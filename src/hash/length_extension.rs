@@ -0,0 +1,123 @@
+//! Length-extension attack on secret-prefix MACs, `H(secret || message)`.
+//!
+//! For Merkle–Damgård hashes the digest is the whole internal state, so an
+//! attacker who knows `H(secret || message)` and the secret length can keep
+//! hashing from there without knowing the secret. HMAC is not affected: the
+//! outer hash hides the inner state.
+
+use crate::hash::padding::{padding, LengthEncoding};
+use crate::hash::{md5, sha256, Output};
+
+/// Forged message (without the secret) and the MAC it will verify against.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Forgery<const N: usize> {
+    pub message: Vec<u8>,
+    pub digest: Output<N>,
+}
+
+pub fn forge_sha256(digest: &Output<32>, secret_len: usize, message: &[u8], suffix: &[u8]) -> Forgery<32> {
+    let mut state = [0u32; 8];
+    for (word, bytes) in state.iter_mut().zip(digest.as_bytes().chunks_exact(4)) {
+        *word = u32::from_be_bytes(bytes.try_into().unwrap());
+    }
+    let mut buffer = sha256::buffer::Buffer::from_state(state);
+
+    let (message, tail) = extend(secret_len, message, suffix, LengthEncoding::BigEndian);
+    for block in tail.chunks_exact(64) {
+        buffer.process_block(&sha256::block::Block512::from(<&[u8; 64]>::try_from(block).unwrap()));
+    }
+    Forgery {
+        message,
+        digest: buffer.get_digest(),
+    }
+}
+
+pub fn forge_md5(digest: &Output<16>, secret_len: usize, message: &[u8], suffix: &[u8]) -> Forgery<16> {
+    let mut state = [0u32; 4];
+    for (word, bytes) in state.iter_mut().zip(digest.as_bytes().chunks_exact(4)) {
+        *word = u32::from_le_bytes(bytes.try_into().unwrap());
+    }
+    let mut buffer = md5::buffer::Buffer::from_state(state);
+
+    let (message, tail) = extend(secret_len, message, suffix, LengthEncoding::LittleEndian);
+    for block in tail.chunks_exact(64) {
+        buffer.process_block(&md5::block::Block512::from(<&[u8; 64]>::try_from(block).unwrap()));
+    }
+    Forgery {
+        message,
+        digest: buffer.get_digest(),
+    }
+}
+
+// Devuelve message || glue || suffix y los bloques que faltan por comprimir:
+// el sufijo con el relleno calculado sobre la longitud total, secreto incluido.
+fn extend(secret_len: usize, message: &[u8], suffix: &[u8], encoding: LengthEncoding) -> (Vec<u8>, Vec<u8>) {
+    let glue = padding::<64>((secret_len + message.len()) as u64, encoding);
+    let mut forged = message.to_vec();
+    forged.extend_from_slice(&glue);
+    forged.extend_from_slice(suffix);
+
+    let mut tail = suffix.to_vec();
+    tail.extend_from_slice(&padding::<64>((secret_len + forged.len()) as u64, encoding));
+    (forged, tail)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hash::Digest;
+    use crate::mac::Hmac;
+
+    const SECRET: &[u8] = b"server-side secret";
+    const MESSAGE: &[u8] = b"user=guest&role=viewer";
+    const SUFFIX: &[u8] = b"&role=admin";
+
+    fn secret_prefix_mac<H: Digest>(message: &[u8]) -> H::Output {
+        let mut hasher = H::new();
+        hasher.update(SECRET);
+        hasher.update(message);
+        hasher.finalize()
+    }
+
+    #[test]
+    fn test_sha256_forgery_verifies() {
+        let mac = secret_prefix_mac::<sha256::Hasher>(MESSAGE);
+        let forgery = forge_sha256(&mac, SECRET.len(), MESSAGE, SUFFIX);
+
+        assert!(forgery.message.starts_with(MESSAGE));
+        assert!(forgery.message.ends_with(SUFFIX));
+        assert_eq!(forgery.digest, secret_prefix_mac::<sha256::Hasher>(&forgery.message));
+    }
+
+    #[test]
+    fn test_md5_forgery_verifies() {
+        let mac = secret_prefix_mac::<md5::Hasher>(MESSAGE);
+        let forgery = forge_md5(&mac, SECRET.len(), MESSAGE, SUFFIX);
+        assert_eq!(forgery.digest, secret_prefix_mac::<md5::Hasher>(&forgery.message));
+    }
+
+    #[test]
+    fn test_secret_length_can_be_guessed() {
+        // El atacante prueba longitudes hasta que el servidor acepta la falsificación.
+        let mac = secret_prefix_mac::<sha256::Hasher>(MESSAGE);
+        let accepted: Vec<usize> = (0..64)
+            .filter(|&guess| {
+                let forgery = forge_sha256(&mac, guess, MESSAGE, SUFFIX);
+                forgery.digest == secret_prefix_mac::<sha256::Hasher>(&forgery.message)
+            })
+            .collect();
+        assert_eq!(accepted, [SECRET.len()]);
+    }
+
+    #[test]
+    fn test_hmac_resists_extension() {
+        let tag = Hmac::<sha256::Hasher>::mac(SECRET, MESSAGE);
+        // Aun acertando la longitud del hash interno (bloque de clave + mensaje), el hash externo lo impide.
+        let forgery = forge_sha256(&tag, SECRET.len() + 64, MESSAGE, SUFFIX);
+
+        let mut hmac = Hmac::<sha256::Hasher>::new(SECRET);
+        hmac.update(&forgery.message);
+        assert!(!hmac.verify(forgery.digest.as_bytes()));
+    }
+}
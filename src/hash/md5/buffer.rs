@@ -1,13 +1,23 @@
 use crate::hash::md5::Block512;
+use crate::hash::Output;
 
 
 
+#[derive(Clone)]
 pub struct Buffer{
     data: [u32; 4]
 }
 
 
 impl Buffer{
+    pub fn from_state(data: [u32; 4]) -> Self {
+        Buffer { data }
+    }
+
+    pub fn state(&self) -> [u32; 4] {
+        self.data
+    }
+
     pub fn new() -> Self {
         Buffer {
             data: [
@@ -81,45 +91,24 @@ impl Buffer{
             };
         }
         self.sum_mod_2_32_inplace(&new_buffer);
-        self.reorganize_bytes_inplace();
-        self.data.reverse();
     }
 
 
-    pub fn get_digest(&self) -> String {
-        self.data
-            .iter()
-            .map(|&word| format!("{:08x}", word))
-            .collect::<Vec<String>>()
-            .concat()
-    }
-
-    pub fn sum_mod_2_32_inplace(&mut self, other: &Buffer) {
-        for i in 0..4 {
-            self.data[i] = self.data[i].wrapping_add(other.data[i]);
+    pub fn get_digest(&self) -> Output<16> {
+        let mut digest = [0u8; 16];
+        for (chunk, word) in digest.chunks_exact_mut(4).zip(self.data.iter()) {
+            chunk.copy_from_slice(&word.to_le_bytes());
         }
+        Output::from(digest)
     }
 
-    pub fn reorganize_bytes_inplace(&mut self) {
-
-        let mut temp = [0u8; 16];
-
-        let bytes: Vec<u8> = self.data.iter()
-            .flat_map(|&n| n.to_le_bytes())
-            .collect();
-
-        for (i, &byte) in bytes.iter().enumerate() {
-            let idx = 15 - i;
-            temp[idx] = byte;
-        }
+    pub fn get_hex_digest(&self) -> String {
+        self.get_digest().to_hex()
+    }
 
+    pub fn sum_mod_2_32_inplace(&mut self, other: &Buffer) {
         for i in 0..4 {
-            self.data[i] = u32::from_le_bytes([
-                temp[i * 4],
-                temp[i * 4 + 1],
-                temp[i * 4 + 2],
-                temp[i * 4 + 3],
-            ]);
+            self.data[i] = self.data[i].wrapping_add(other.data[i]);
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,102 @@
+use crate::hash::md5::{Block512, Buffer};
+use crate::hash::padding::{BlockBuffer, LengthEncoding};
+use crate::hash::midstate::{Algorithm, Midstate, MidstateError};
+use crate::hash::{Digest, Output};
+
+/// Incremental MD5: feed the message with `update` and pad it in `finalize`.
+#[derive(Clone)]
+pub struct Hasher {
+    buffer: Buffer,
+    blocks: BlockBuffer<64>,
+}
+
+impl Digest for Hasher {
+    type Output = Output<16>;
+
+    const OUTPUT_SIZE: usize = 16;
+    const BLOCK_SIZE: usize = 64;
+
+    fn new() -> Self {
+        Hasher {
+            buffer: Buffer::new(),
+            blocks: BlockBuffer::new(),
+        }
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        let buffer = &mut self.buffer;
+        self.blocks.update(data, |block| buffer.process_block(&Block512::from(block)));
+    }
+
+    fn finalize(mut self) -> Output<16> {
+        let buffer = &mut self.buffer;
+        self.blocks.finalize(LengthEncoding::LittleEndian, |block| buffer.process_block(&Block512::from(block)));
+        self.buffer.get_digest()
+    }
+
+    fn reset(&mut self) {
+        *self = Hasher::new();
+    }
+}
+
+impl Hasher {
+    pub fn export_midstate(&self) -> Midstate {
+        Midstate::new(Algorithm::Md5, &self.buffer.state(), self.blocks.len(), self.blocks.pending())
+    }
+
+    pub fn resume(midstate: &Midstate) -> Result<Self, MidstateError> {
+        let state = midstate.state_for::<4>(Algorithm::Md5)?;
+        Ok(Hasher {
+            buffer: Buffer::from_state(state),
+            blocks: BlockBuffer::resume(midstate.len(), midstate.pending()),
+        })
+    }
+}
+
+impl Default for Hasher {
+    fn default() -> Self {
+        Hasher::new()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn md5_hex(data: &[u8]) -> String {
+        let mut hasher = Hasher::new();
+        hasher.update(data);
+        hasher.finalize().to_hex()
+    }
+
+    #[test]
+    fn test_rfc1321_vectors() {
+        assert_eq!(md5_hex(b""), "d41d8cd98f00b204e9800998ecf8427e");
+        assert_eq!(md5_hex(b"a"), "0cc175b9c0f1b6a831c399e269772661");
+        assert_eq!(md5_hex(b"abc"), "900150983cd24fb0d6963f7d28e17f72");
+        assert_eq!(md5_hex(b"message digest"), "f96b697d7cb7938d525a2f31aaf161d0");
+        assert_eq!(
+            md5_hex(b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789"),
+            "d174ab98d277d9f5a5611c2c9f419d9f"
+        );
+        assert_eq!(
+            md5_hex(b"12345678901234567890123456789012345678901234567890123456789012345678901234567890"),
+            "57edf4a22be3c955ac49da2e2107b67a"
+        );
+    }
+
+    #[test]
+    fn test_chunked_updates_match_single_update() {
+        let message: Vec<u8> = (0..1000u32).map(|i| (i % 251) as u8).collect();
+        let expected = md5_hex(&message);
+
+        for chunk_size in [1, 3, 63, 64, 65, 200] {
+            let mut hasher = Hasher::new();
+            for chunk in message.chunks(chunk_size) {
+                hasher.update(chunk);
+            }
+            assert_eq!(hasher.finalize().to_hex(), expected);
+        }
+    }
+}
@@ -1,13 +1,17 @@
 use buffer::Buffer;
 use crate::hash::md5::block::{Block512, BlockParseError};
 use crate::hash::md5::raw::RawData;
+use crate::hash::Output;
 
 pub mod buffer;
+pub mod hasher;
 pub mod block;
 pub mod raw;
 
+pub use hasher::Hasher;
 
-struct MD5 {
+
+pub struct MD5 {
     blocks: Vec<Block512>,
     buffer: Buffer,
     calculated: bool
@@ -34,20 +38,26 @@ impl MD5 {
         for block in &self.blocks{
             self.buffer.process_block(block);
         }
+        self.calculated = true;
     }
 
-    pub fn get_digest(& mut self) -> String{
+    pub fn get_digest(& mut self) -> Output<16>{
         if !self.calculated {
             self.calculate_digest();
         }
         self.buffer.get_digest()
     }
 
+    pub fn get_hex_digest(& mut self) -> String{
+        self.get_digest().to_hex()
+    }
+
 }
 
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
+    use crate::hash::Digest;
 
     use super::*;
 
@@ -57,7 +67,7 @@ mod tests {
         let mut md5 = MD5::new(raw_data).expect("Failed to create MD5 instance");
 
         // Calculate digest
-        let digest = md5.get_digest();
+        let digest = md5.get_hex_digest();
         // Compare the result with the expected digest
         assert_eq!(digest, "5f5fe9abdaaad9e5da6f9a661fceae81");
     }
@@ -68,7 +78,7 @@ mod tests {
         let mut md5 = MD5::new(raw_data).expect("Failed to create MD5 instance");
 
         // Calculate digest
-        let digest = md5.get_digest();
+        let digest = md5.get_hex_digest();
         // Compare the result with the expected digest
         assert_eq!(digest, "d501194c987486789bb01b50dc1a0adb");
     }
@@ -80,7 +90,7 @@ mod tests {
         let mut md5 = MD5::new(raw_data).expect("Failed to create MD5 instance");
 
         // Calculate digest
-        let digest = md5.get_digest();
+        let digest = md5.get_hex_digest();
         // Compare the result with the expected digest
         assert_eq!(digest, "5df9f63916ebf8528697b629022993e8");
     }
@@ -91,8 +101,15 @@ mod tests {
         let mut md5 = MD5::new(raw_data).expect("Failed to create MD5 instance");
 
         // Calculate digest
-        let digest = md5.get_digest();
+        let digest = md5.get_hex_digest();
         // Compare the result with the expected digest
         assert_eq!(digest, "e14a3ff5b5e67ede599cac94358e1028");
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_md5_binary_multi_block() {
+        let bytes: Vec<u8> = (0..=255).collect();
+        let mut md5 = MD5::new(RawData::from(bytes.as_slice())).expect("Failed to create MD5 instance");
+        assert_eq!(md5.get_digest(), Hasher::digest(&bytes));
+    }
+}
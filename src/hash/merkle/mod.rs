@@ -0,0 +1,5 @@
+pub mod proof;
+pub mod tree;
+
+pub use proof::{verify_consistency, verify_inclusion};
+pub use tree::{leaf_hash, node_hash, MerkleError, MerkleTree};
@@ -0,0 +1,162 @@
+use crate::hash::merkle::tree::node_hash;
+use crate::hash::Output;
+
+/// Checks an audit path for `leaf_hash` at `index` against the root of a tree of `tree_size` leaves.
+pub fn verify_inclusion(
+    leaf_hash: &Output<32>,
+    index: usize,
+    tree_size: usize,
+    path: &[Output<32>],
+    root: &Output<32>,
+) -> bool {
+    if index >= tree_size {
+        return false;
+    }
+
+    // fn y sn recorren el camino de la hoja y del último nodo hacia la raíz (RFC 9162, 2.1.3.2).
+    let mut fn_ = index;
+    let mut sn = tree_size - 1;
+    let mut hash = *leaf_hash;
+    for sibling in path {
+        if sn == 0 {
+            return false;
+        }
+        if fn_ & 1 == 1 || fn_ == sn {
+            hash = node_hash(sibling, &hash);
+            while fn_ & 1 == 0 && fn_ != 0 {
+                fn_ >>= 1;
+                sn >>= 1;
+            }
+        } else {
+            hash = node_hash(&hash, sibling);
+        }
+        fn_ >>= 1;
+        sn >>= 1;
+    }
+    sn == 0 && hash == *root
+}
+
+/// Checks that the tree with `old_root` over `old_size` leaves is a prefix of the one with `new_root`.
+pub fn verify_consistency(
+    old_size: usize,
+    new_size: usize,
+    old_root: &Output<32>,
+    new_root: &Output<32>,
+    proof: &[Output<32>],
+) -> bool {
+    if old_size == 0 || old_size > new_size {
+        return false;
+    }
+    if old_size == new_size {
+        return proof.is_empty() && old_root == new_root;
+    }
+
+    // Si el árbol viejo es un subárbol completo, su raíz no viaja en la prueba.
+    let mut nodes = Vec::with_capacity(proof.len() + 1);
+    if old_size.is_power_of_two() {
+        nodes.push(*old_root);
+    }
+    nodes.extend_from_slice(proof);
+    let Some((first, rest)) = nodes.split_first() else {
+        return false;
+    };
+
+    let mut fn_ = old_size - 1;
+    let mut sn = new_size - 1;
+    while fn_ & 1 == 1 {
+        fn_ >>= 1;
+        sn >>= 1;
+    }
+
+    let mut old_hash = *first;
+    let mut new_hash = *first;
+    for node in rest {
+        if sn == 0 {
+            return false;
+        }
+        if fn_ & 1 == 1 || fn_ == sn {
+            old_hash = node_hash(node, &old_hash);
+            new_hash = node_hash(node, &new_hash);
+            while fn_ & 1 == 0 && fn_ != 0 {
+                fn_ >>= 1;
+                sn >>= 1;
+            }
+        } else {
+            new_hash = node_hash(&new_hash, node);
+        }
+        fn_ >>= 1;
+        sn >>= 1;
+    }
+    sn == 0 && old_hash == *old_root && new_hash == *new_root
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hash::merkle::tree::{leaf_hash, MerkleTree};
+
+    fn sample_tree(size: usize) -> MerkleTree {
+        MerkleTree::from_leaves((0..size).map(|i| format!("build #{}", i)))
+    }
+
+    #[test]
+    fn test_every_audit_path_verifies() {
+        let tree = sample_tree(13);
+        for size in 1..=13 {
+            let root = tree.root_at(size).unwrap();
+            for index in 0..size {
+                let path = tree.audit_path(index, size).unwrap();
+                assert!(verify_inclusion(tree.leaf(index).unwrap(), index, size, &path, &root));
+            }
+        }
+    }
+
+    #[test]
+    fn test_tampered_inclusion_fails() {
+        let tree = sample_tree(7);
+        let root = tree.root();
+        let path = tree.audit_path(3, 7).unwrap();
+        let leaf = tree.leaf(3).unwrap();
+
+        assert!(!verify_inclusion(&leaf_hash(b"build #99"), 3, 7, &path, &root));
+        assert!(!verify_inclusion(leaf, 4, 7, &path, &root));
+        assert!(!verify_inclusion(leaf, 3, 4, &path, &root));
+        assert!(!verify_inclusion(leaf, 3, 7, &path[..2], &root));
+        assert!(!verify_inclusion(leaf, 7, 7, &path, &root));
+
+        let mut forged = path.clone();
+        forged[1] = leaf_hash(b"forged");
+        assert!(!verify_inclusion(leaf, 3, 7, &forged, &root));
+    }
+
+    #[test]
+    fn test_every_consistency_proof_verifies() {
+        let tree = sample_tree(13);
+        for new_size in 1..=13 {
+            let new_root = tree.root_at(new_size).unwrap();
+            for old_size in 1..=new_size {
+                let old_root = tree.root_at(old_size).unwrap();
+                let proof = tree.consistency_proof(old_size, new_size).unwrap();
+                assert!(verify_consistency(old_size, new_size, &old_root, &new_root, &proof));
+            }
+        }
+    }
+
+    #[test]
+    fn test_rewritten_history_fails_consistency() {
+        let tree = sample_tree(8);
+        let mut rewritten = sample_tree(5);
+        rewritten.push(b"tampered");
+        rewritten.push(b"build #6");
+        rewritten.push(b"build #7");
+
+        let proof = rewritten.consistency_proof(6, 8).unwrap();
+        assert!(!verify_consistency(6, 8, &tree.root_at(6).unwrap(), &rewritten.root(), &proof));
+
+        let proof = tree.consistency_proof(3, 8).unwrap();
+        assert!(!verify_consistency(3, 8, &tree.root_at(3).unwrap(), &tree.root_at(7).unwrap(), &proof));
+        assert!(!verify_consistency(4, 8, &tree.root_at(3).unwrap(), &tree.root(), &proof));
+        assert!(!verify_consistency(0, 8, &tree.root_at(3).unwrap(), &tree.root(), &proof));
+    }
+}
@@ -0,0 +1,237 @@
+use crate::hash::{sha256, Digest, Output};
+
+const LEAF_PREFIX: u8 = 0x00;
+const NODE_PREFIX: u8 = 0x01;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum MerkleError {
+    IndexOutOfRange(usize),
+    InvalidTreeSize(usize),
+}
+
+// Los prefijos separan hojas de nodos internos y evitan segundas preimágenes.
+pub fn leaf_hash(data: &[u8]) -> Output<32> {
+    let mut hasher = sha256::Hasher::new();
+    hasher.update(&[LEAF_PREFIX]);
+    hasher.update(data);
+    hasher.finalize()
+}
+
+pub fn node_hash(left: &Output<32>, right: &Output<32>) -> Output<32> {
+    let mut hasher = sha256::Hasher::new();
+    hasher.update(&[NODE_PREFIX]);
+    hasher.update(left.as_bytes());
+    hasher.update(right.as_bytes());
+    hasher.finalize()
+}
+
+/// Append-only Merkle tree over SHA-256 (RFC 6962) that keeps the leaf hashes
+/// so it can answer for any earlier tree size.
+#[derive(Clone, Default)]
+pub struct MerkleTree {
+    leaves: Vec<Output<32>>,
+}
+
+impl MerkleTree {
+    pub fn new() -> Self {
+        MerkleTree { leaves: Vec::new() }
+    }
+
+    pub fn from_leaves<I, T>(leaves: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: AsRef<[u8]>,
+    {
+        MerkleTree {
+            leaves: leaves.into_iter().map(|leaf| leaf_hash(leaf.as_ref())).collect(),
+        }
+    }
+
+    // Devuelve el índice de la hoja añadida.
+    pub fn push(&mut self, data: &[u8]) -> usize {
+        self.leaves.push(leaf_hash(data));
+        self.leaves.len() - 1
+    }
+
+    pub fn len(&self) -> usize {
+        self.leaves.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.leaves.is_empty()
+    }
+
+    pub fn leaf(&self, index: usize) -> Option<&Output<32>> {
+        self.leaves.get(index)
+    }
+
+    pub fn root(&self) -> Output<32> {
+        subtree_root(&self.leaves)
+    }
+
+    pub fn root_at(&self, size: usize) -> Result<Output<32>, MerkleError> {
+        if size > self.leaves.len() {
+            return Err(MerkleError::InvalidTreeSize(size));
+        }
+        Ok(subtree_root(&self.leaves[..size]))
+    }
+
+    /// Audit path for leaf `index` in the tree made of the first `size` leaves.
+    pub fn audit_path(&self, index: usize, size: usize) -> Result<Vec<Output<32>>, MerkleError> {
+        if size > self.leaves.len() {
+            return Err(MerkleError::InvalidTreeSize(size));
+        }
+        if index >= size {
+            return Err(MerkleError::IndexOutOfRange(index));
+        }
+        Ok(path(index, &self.leaves[..size]))
+    }
+
+    /// Proof that the tree of `old_size` leaves is a prefix of the one with `new_size`.
+    pub fn consistency_proof(&self, old_size: usize, new_size: usize) -> Result<Vec<Output<32>>, MerkleError> {
+        if new_size > self.leaves.len() {
+            return Err(MerkleError::InvalidTreeSize(new_size));
+        }
+        if old_size == 0 || old_size > new_size {
+            return Err(MerkleError::InvalidTreeSize(old_size));
+        }
+        Ok(subproof(old_size, &self.leaves[..new_size], true))
+    }
+}
+
+// Mayor potencia de dos estrictamente menor que n (n > 1).
+fn split_point(n: usize) -> usize {
+    1 << (usize::BITS - 1 - (n - 1).leading_zeros())
+}
+
+fn subtree_root(leaves: &[Output<32>]) -> Output<32> {
+    match leaves.len() {
+        0 => sha256::Hasher::digest(&[]),
+        1 => leaves[0],
+        n => {
+            let (left, right) = leaves.split_at(split_point(n));
+            node_hash(&subtree_root(left), &subtree_root(right))
+        }
+    }
+}
+
+fn path(index: usize, leaves: &[Output<32>]) -> Vec<Output<32>> {
+    if leaves.len() <= 1 {
+        return Vec::new();
+    }
+    let k = split_point(leaves.len());
+    let (left, right) = leaves.split_at(k);
+    let mut proof;
+    if index < k {
+        proof = path(index, left);
+        proof.push(subtree_root(right));
+    } else {
+        proof = path(index - k, right);
+        proof.push(subtree_root(left));
+    }
+    proof
+}
+
+// SUBPROOF(m, D[n], b) de RFC 6962, sección 2.1.2.
+fn subproof(m: usize, leaves: &[Output<32>], complete: bool) -> Vec<Output<32>> {
+    if m == leaves.len() {
+        return if complete { Vec::new() } else { vec![subtree_root(leaves)] };
+    }
+    let k = split_point(leaves.len());
+    let (left, right) = leaves.split_at(k);
+    let mut proof;
+    if m <= k {
+        proof = subproof(m, left, complete);
+        proof.push(subtree_root(right));
+    } else {
+        proof = subproof(m - k, right, false);
+        proof.push(subtree_root(left));
+    }
+    proof
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Datos de prueba de la implementación de referencia de Certificate Transparency.
+    fn reference_leaves() -> Vec<Vec<u8>> {
+        vec![
+            vec![],
+            vec![0x00],
+            vec![0x10],
+            vec![0x20, 0x21],
+            vec![0x30, 0x31],
+            vec![0x40, 0x41, 0x42, 0x43],
+            (0x50..0x58).collect(),
+            (0x60..0x70).collect(),
+        ]
+    }
+
+    #[test]
+    fn test_reference_roots() {
+        let tree = MerkleTree::from_leaves(reference_leaves());
+        let expected = [
+            "6e340b9cffb37a989ca544e6bb780a2c78901d3fb33738768511a30617afa01d",
+            "fac54203e7cc696cf0dfcb42c92a1d9dbaf70ad9e621f4bd8d98662f00e3c125",
+            "aeb6bcfe274b70a14fb067a5e5578264db0fa9b51af5e0ba159158f329e06e77",
+            "d37ee418976dd95753c1c73862b9398fa2a2cf9b4ff0fdfe8b30cd95209614b7",
+            "4e3bbb1f7b478dcfe71fb631631519a3bca12c9aefca1612bfce4c13a86264d4",
+            "76e67dadbcdf1e10e1b74ddc608abd2f98dfb16fbce75277b5232a127f2087ef",
+            "ddb89be403809e325750d3d263cd78929c2942b7942a34b77e122c9594a74c8c",
+            "5dc9da79a70659a9ad559cb701ded9a2ab9d823aad2f4960cfe370eff4604328",
+        ];
+        for (size, root) in expected.iter().enumerate() {
+            assert_eq!(tree.root_at(size + 1).unwrap().to_hex(), *root);
+        }
+        assert_eq!(tree.root().to_hex(), expected[7]);
+        assert_eq!(MerkleTree::new().root(), sha256::Hasher::digest(b""));
+    }
+
+    #[test]
+    fn test_audit_path_and_consistency_proof() {
+        let tree = MerkleTree::from_leaves(reference_leaves());
+        let path: Vec<String> = tree.audit_path(2, 7).unwrap().iter().map(Output::to_hex).collect();
+        assert_eq!(
+            path,
+            [
+                "07506a85fd9dd2f120eb694f86011e5bb4662e5c415a62917033d4a9624487e7",
+                "fac54203e7cc696cf0dfcb42c92a1d9dbaf70ad9e621f4bd8d98662f00e3c125",
+                "837dbb152e9b079010717e84e865da4ebc0fa198a806d59d31bf15accef22d0e",
+            ]
+        );
+
+        let proof: Vec<String> = tree.consistency_proof(3, 7).unwrap().iter().map(Output::to_hex).collect();
+        assert_eq!(
+            proof,
+            [
+                "0298d122906dcfc10892cb53a73992fc5b9f493ea4c9badb27b791b4127a7fe7",
+                "07506a85fd9dd2f120eb694f86011e5bb4662e5c415a62917033d4a9624487e7",
+                "fac54203e7cc696cf0dfcb42c92a1d9dbaf70ad9e621f4bd8d98662f00e3c125",
+                "837dbb152e9b079010717e84e865da4ebc0fa198a806d59d31bf15accef22d0e",
+            ]
+        );
+        assert!(tree.consistency_proof(4, 4).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_push_matches_from_leaves() {
+        let mut tree = MerkleTree::new();
+        for (i, leaf) in reference_leaves().iter().enumerate() {
+            assert_eq!(tree.push(leaf), i);
+        }
+        assert_eq!(tree.len(), 8);
+        assert_eq!(tree.root(), MerkleTree::from_leaves(reference_leaves()).root());
+    }
+
+    #[test]
+    fn test_invalid_sizes() {
+        let tree = MerkleTree::from_leaves(reference_leaves());
+        assert_eq!(tree.root_at(9), Err(MerkleError::InvalidTreeSize(9)));
+        assert_eq!(tree.audit_path(7, 7), Err(MerkleError::IndexOutOfRange(7)));
+        assert_eq!(tree.audit_path(0, 9), Err(MerkleError::InvalidTreeSize(9)));
+        assert_eq!(tree.consistency_proof(0, 8), Err(MerkleError::InvalidTreeSize(0)));
+        assert_eq!(tree.consistency_proof(5, 4), Err(MerkleError::InvalidTreeSize(5)));
+    }
+}
@@ -0,0 +1,200 @@
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Algorithm {
+    Md5,
+    Sha256,
+}
+
+impl Algorithm {
+    fn id(&self) -> u8 {
+        match self {
+            Algorithm::Md5 => 1,
+            Algorithm::Sha256 => 2,
+        }
+    }
+
+    fn from_id(id: u8) -> Option<Self> {
+        match id {
+            1 => Some(Algorithm::Md5),
+            2 => Some(Algorithm::Sha256),
+            _ => None,
+        }
+    }
+
+    fn state_words(&self) -> usize {
+        match self {
+            Algorithm::Md5 => 4,
+            Algorithm::Sha256 => 8,
+        }
+    }
+}
+
+impl fmt::Display for Algorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Algorithm::Md5 => f.write_str("MD5"),
+            Algorithm::Sha256 => f.write_str("SHA-256"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum MidstateError {
+    AlgorithmMismatch { expected: Algorithm, found: Algorithm },
+    UnknownAlgorithm(u8),
+    InvalidLength(usize),
+}
+
+const BLOCK_SIZE: u64 = 64;
+const HEADER_LEN: usize = 9;
+
+/// Checkpoint of an unfinished hash: chaining values, bytes absorbed so far
+/// and the tail that does not fill a block yet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Midstate {
+    algorithm: Algorithm,
+    state: Vec<u32>,
+    length: u64,
+    pending: Vec<u8>,
+}
+
+impl Midstate {
+    pub(crate) fn new(algorithm: Algorithm, state: &[u32], length: u64, pending: &[u8]) -> Self {
+        Midstate {
+            algorithm,
+            state: state.to_vec(),
+            length,
+            pending: pending.to_vec(),
+        }
+    }
+
+    pub fn algorithm(&self) -> Algorithm {
+        self.algorithm
+    }
+
+    pub fn len(&self) -> u64 {
+        self.length
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    pub(crate) fn pending(&self) -> &[u8] {
+        &self.pending
+    }
+
+    // Solo devuelve los valores encadenados si la instantánea es del algoritmo pedido.
+    pub(crate) fn state_for<const W: usize>(&self, expected: Algorithm) -> Result<[u32; W], MidstateError> {
+        if self.algorithm != expected {
+            return Err(MidstateError::AlgorithmMismatch {
+                expected,
+                found: self.algorithm,
+            });
+        }
+        Ok(self.state[..].try_into().expect("state size is fixed by the algorithm"))
+    }
+
+    // Formato: id del algoritmo, longitud en bytes (u64 BE), palabras de estado (u32 BE) y bytes pendientes.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(HEADER_LEN + 4 * self.state.len() + self.pending.len());
+        bytes.push(self.algorithm.id());
+        bytes.extend_from_slice(&self.length.to_be_bytes());
+        for word in &self.state {
+            bytes.extend_from_slice(&word.to_be_bytes());
+        }
+        bytes.extend_from_slice(&self.pending);
+        bytes
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, MidstateError> {
+        if bytes.len() < HEADER_LEN {
+            return Err(MidstateError::InvalidLength(bytes.len()));
+        }
+        let algorithm = Algorithm::from_id(bytes[0]).ok_or(MidstateError::UnknownAlgorithm(bytes[0]))?;
+        let length = u64::from_be_bytes(bytes[1..HEADER_LEN].try_into().unwrap());
+
+        let state_end = HEADER_LEN + 4 * algorithm.state_words();
+        if bytes.len() as u64 != state_end as u64 + length % BLOCK_SIZE {
+            return Err(MidstateError::InvalidLength(bytes.len()));
+        }
+
+        let state: Vec<u32> = bytes[HEADER_LEN..state_end]
+            .chunks_exact(4)
+            .map(|word| u32::from_be_bytes(word.try_into().unwrap()))
+            .collect();
+        Ok(Midstate::new(algorithm, &state, length, &bytes[state_end..]))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hash::{md5, sha256, Digest};
+
+    fn message() -> Vec<u8> {
+        (0..1000u32).map(|i| (i * 7 % 256) as u8).collect()
+    }
+
+    #[test]
+    fn test_sha256_resume_after_serialization() {
+        let message = message();
+        for split in [0, 1, 63, 64, 100, 999] {
+            let mut hasher = sha256::Hasher::new();
+            hasher.update(&message[..split]);
+            let bytes = hasher.export_midstate().to_bytes();
+
+            let midstate = Midstate::from_bytes(&bytes).unwrap();
+            assert_eq!(midstate.len(), split as u64);
+            let mut resumed = sha256::Hasher::resume(&midstate).unwrap();
+            resumed.update(&message[split..]);
+            assert_eq!(resumed.finalize(), sha256::Hasher::digest(&message));
+        }
+    }
+
+    #[test]
+    fn test_md5_resume_after_serialization() {
+        let message = message();
+        for split in [0, 5, 64, 130] {
+            let mut hasher = md5::Hasher::new();
+            hasher.update(&message[..split]);
+            let midstate = Midstate::from_bytes(&hasher.export_midstate().to_bytes()).unwrap();
+
+            let mut resumed = md5::Hasher::resume(&midstate).unwrap();
+            resumed.update(&message[split..]);
+            assert_eq!(resumed.finalize(), md5::Hasher::digest(&message));
+        }
+    }
+
+    #[test]
+    fn test_algorithm_mismatch() {
+        let mut hasher = md5::Hasher::new();
+        hasher.update(b"partial upload");
+        let midstate = hasher.export_midstate();
+        assert_eq!(midstate.algorithm(), Algorithm::Md5);
+        assert_eq!(
+            sha256::Hasher::resume(&midstate).err(),
+            Some(MidstateError::AlgorithmMismatch {
+                expected: Algorithm::Sha256,
+                found: Algorithm::Md5,
+            })
+        );
+    }
+
+    #[test]
+    fn test_malformed_bytes() {
+        let mut hasher = sha256::Hasher::new();
+        hasher.update(b"abc");
+        let bytes = hasher.export_midstate().to_bytes();
+        assert_eq!(bytes.len(), 9 + 32 + 3);
+
+        assert_eq!(Midstate::from_bytes(&bytes[..5]), Err(MidstateError::InvalidLength(5)));
+        assert_eq!(Midstate::from_bytes(&bytes[..43]), Err(MidstateError::InvalidLength(43)));
+
+        let mut unknown = bytes.clone();
+        unknown[0] = 9;
+        assert_eq!(Midstate::from_bytes(&unknown), Err(MidstateError::UnknownAlgorithm(9)));
+    }
+}
@@ -1,2 +1,21 @@
+pub mod digest;
+pub mod output;
+pub mod padding;
+pub mod blake2;
+pub mod blake3;
+pub mod length_extension;
 pub mod md5;
-pub mod sha256;
\ No newline at end of file
+pub mod merkle;
+pub mod midstate;
+pub mod ripemd160;
+pub mod sha1;
+pub mod sha224;
+pub mod sha256;
+pub mod sha3;
+pub mod sha384;
+pub mod sha512;
+pub mod sha512_224;
+pub mod sha512_256;
+
+pub use digest::Digest;
+pub use output::Output;
@@ -0,0 +1,141 @@
+use std::fmt;
+
+const BASE64_ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+#[derive(Clone, Copy)]
+pub struct Output<const N: usize> {
+    data: [u8; N],
+}
+
+impl<const N: usize> Output<N> {
+    pub fn as_bytes(&self) -> &[u8; N] {
+        &self.data
+    }
+
+    pub fn into_bytes(self) -> [u8; N] {
+        self.data
+    }
+
+    pub fn to_hex(&self) -> String {
+        self.data.iter().map(|byte| format!("{:02x}", byte)).collect()
+    }
+
+    pub fn to_upper_hex(&self) -> String {
+        self.data.iter().map(|byte| format!("{:02X}", byte)).collect()
+    }
+
+    pub fn to_base64(&self) -> String {
+        let mut result = String::with_capacity(N.div_ceil(3) * 4);
+        for chunk in self.data.chunks(3) {
+            let b0 = chunk[0] as u32;
+            let b1 = chunk.get(1).copied().unwrap_or(0) as u32;
+            let b2 = chunk.get(2).copied().unwrap_or(0) as u32;
+            let triple = (b0 << 16) | (b1 << 8) | b2;
+
+            for i in 0..4 {
+                if i <= chunk.len() {
+                    let index = (triple >> (18 - 6 * i)) & 0x3F;
+                    result.push(BASE64_ALPHABET[index as usize] as char);
+                } else {
+                    result.push('=');
+                }
+            }
+        }
+        result
+    }
+
+    pub fn ct_eq(&self, other: &[u8]) -> bool {
+        constant_time_eq(&self.data, other)
+    }
+}
+
+// Compara sin salir en el primer byte distinto; solo la longitud es pública.
+pub fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut diff = 0u8;
+    for (x, y) in a.iter().zip(b.iter()) {
+        diff |= x ^ y;
+    }
+    diff == 0
+}
+
+impl<const N: usize> From<[u8; N]> for Output<N> {
+    fn from(data: [u8; N]) -> Self {
+        Output { data }
+    }
+}
+
+impl<const N: usize> AsRef<[u8]> for Output<N> {
+    fn as_ref(&self) -> &[u8] {
+        &self.data
+    }
+}
+
+impl<const N: usize> PartialEq for Output<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(&other.data)
+    }
+}
+
+impl<const N: usize> Eq for Output<N> {}
+
+impl<const N: usize> fmt::Display for Output<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_hex())
+    }
+}
+
+impl<const N: usize> fmt::Debug for Output<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Output({})", self.to_hex())
+    }
+}
+
+impl<const N: usize> fmt::LowerHex for Output<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_hex())
+    }
+}
+
+impl<const N: usize> fmt::UpperHex for Output<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_upper_hex())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hex_rendering() {
+        let output = Output::from([0x00, 0xab, 0x10, 0xff]);
+        assert_eq!(output.to_hex(), "00ab10ff");
+        assert_eq!(output.to_upper_hex(), "00AB10FF");
+        assert_eq!(format!("{}", output), "00ab10ff");
+        assert_eq!(format!("{:X}", output), "00AB10FF");
+    }
+
+    #[test]
+    fn test_base64_rendering() {
+        assert_eq!(Output::from(*b"").to_base64(), "");
+        assert_eq!(Output::from(*b"f").to_base64(), "Zg==");
+        assert_eq!(Output::from(*b"fo").to_base64(), "Zm8=");
+        assert_eq!(Output::from(*b"foo").to_base64(), "Zm9v");
+        assert_eq!(Output::from(*b"foob").to_base64(), "Zm9vYg==");
+        assert_eq!(Output::from(*b"fooba").to_base64(), "Zm9vYmE=");
+        assert_eq!(Output::from(*b"foobar").to_base64(), "Zm9vYmFy");
+    }
+
+    #[test]
+    fn test_equality() {
+        let a = Output::from([1u8, 2, 3]);
+        assert_eq!(a, Output::from([1u8, 2, 3]));
+        assert_ne!(a, Output::from([1u8, 2, 4]));
+        assert!(a.ct_eq(&[1, 2, 3]));
+        assert!(!a.ct_eq(&[1, 2]));
+    }
+}
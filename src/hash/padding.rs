@@ -0,0 +1,163 @@
+// Relleno Merkle–Damgård compartido: 0x80, ceros y la longitud del mensaje en bits.
+// El campo de longitud ocupa N / 8 bytes (8 para bloques de 64, 16 para bloques de 128).
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LengthEncoding {
+    LittleEndian,
+    BigEndian,
+}
+
+pub fn padding<const N: usize>(message_len: u64, encoding: LengthEncoding) -> Vec<u8> {
+    let field_len = N / 8;
+    let used = (message_len % N as u64) as usize;
+    let zeros = (2 * N - field_len - 1 - used) % N;
+
+    let mut padding = vec![0x80];
+    padding.resize(1 + zeros, 0x00);
+
+    let length_bits = (message_len as u128).wrapping_mul(8);
+    match encoding {
+        LengthEncoding::LittleEndian => {
+            padding.extend_from_slice(&length_bits.to_le_bytes()[..field_len])
+        }
+        LengthEncoding::BigEndian => {
+            padding.extend_from_slice(&length_bits.to_be_bytes()[16 - field_len..])
+        }
+    }
+    padding
+}
+
+pub fn pad_message<const N: usize>(mut bytes: Vec<u8>, encoding: LengthEncoding) -> Vec<u8> {
+    let tail = padding::<N>(bytes.len() as u64, encoding);
+    bytes.extend_from_slice(&tail);
+    bytes
+}
+
+#[derive(Clone)]
+pub struct BlockBuffer<const N: usize> {
+    pending: [u8; N],
+    pending_len: usize,
+    length: u64,
+}
+
+impl<const N: usize> BlockBuffer<N> {
+    pub fn new() -> Self {
+        BlockBuffer {
+            pending: [0u8; N],
+            pending_len: 0,
+            length: 0,
+        }
+    }
+
+    pub fn len(&self) -> u64 {
+        self.length
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    pub fn update(&mut self, data: &[u8], compress: impl FnMut(&[u8; N])) {
+        self.length = self.length.wrapping_add(data.len() as u64);
+        self.absorb(data, compress);
+    }
+
+    pub fn finalize(&mut self, encoding: LengthEncoding, compress: impl FnMut(&[u8; N])) {
+        let tail = padding::<N>(self.length, encoding);
+        self.absorb(&tail, compress);
+    }
+
+    pub fn reset(&mut self) {
+        *self = BlockBuffer::new();
+    }
+
+    // Bytes recibidos que aún no completan un bloque.
+    pub fn pending(&self) -> &[u8] {
+        &self.pending[..self.pending_len]
+    }
+
+    // Reconstruye el búfer tras `length` bytes; `pending` son los últimos length % N.
+    pub fn resume(length: u64, pending: &[u8]) -> Self {
+        assert_eq!(pending.len() as u64, length % N as u64, "pending bytes must match the length");
+        let mut buffer = BlockBuffer::new();
+        buffer.pending[..pending.len()].copy_from_slice(pending);
+        buffer.pending_len = pending.len();
+        buffer.length = length;
+        buffer
+    }
+
+    fn absorb(&mut self, mut data: &[u8], mut compress: impl FnMut(&[u8; N])) {
+        if self.pending_len > 0 {
+            let take = (N - self.pending_len).min(data.len());
+            self.pending[self.pending_len..self.pending_len + take].copy_from_slice(&data[..take]);
+            self.pending_len += take;
+            data = &data[take..];
+
+            if self.pending_len < N {
+                return;
+            }
+            compress(&self.pending);
+            self.pending_len = 0;
+        }
+
+        let mut chunks = data.chunks_exact(N);
+        for chunk in &mut chunks {
+            compress(chunk.try_into().unwrap());
+        }
+
+        let rest = chunks.remainder();
+        self.pending[..rest.len()].copy_from_slice(rest);
+        self.pending_len = rest.len();
+    }
+}
+
+impl<const N: usize> Default for BlockBuffer<N> {
+    fn default() -> Self {
+        BlockBuffer::new()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_padding_fills_to_block_boundary() {
+        for len in 0..200u64 {
+            assert_eq!((len as usize + padding::<64>(len, LengthEncoding::BigEndian).len()) % 64, 0);
+            assert_eq!((len as usize + padding::<128>(len, LengthEncoding::BigEndian).len()) % 128, 0);
+        }
+    }
+
+    #[test]
+    fn test_padding_length_field() {
+        let tail = padding::<64>(3, LengthEncoding::LittleEndian);
+        assert_eq!(tail.len(), 61);
+        assert_eq!(tail[0], 0x80);
+        assert_eq!(tail[53..], [0x18, 0, 0, 0, 0, 0, 0, 0]);
+
+        let tail = padding::<128>(3, LengthEncoding::BigEndian);
+        assert_eq!(tail.len(), 125);
+        assert_eq!(tail[109..], [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x18]);
+    }
+
+    #[test]
+    fn test_padding_spills_into_extra_block() {
+        assert_eq!(padding::<64>(55, LengthEncoding::BigEndian).len(), 9);
+        assert_eq!(padding::<64>(56, LengthEncoding::BigEndian).len(), 72);
+    }
+
+    #[test]
+    fn test_block_buffer_counts_blocks() {
+        let mut buffer = BlockBuffer::<64>::new();
+        let mut blocks = 0;
+        buffer.update(&[0u8; 100], |_| blocks += 1);
+        assert_eq!(blocks, 1);
+        buffer.update(&[0u8; 28], |_| blocks += 1);
+        assert_eq!(blocks, 2);
+        assert_eq!(buffer.len(), 128);
+        buffer.finalize(LengthEncoding::BigEndian, |_| blocks += 1);
+        assert_eq!(blocks, 3);
+    }
+}
@@ -0,0 +1,131 @@
+use crate::hash::md5::block::Block512;
+use crate::hash::Output;
+
+// Orden de las palabras del mensaje en cada ronda, línea izquierda y derecha.
+const R_LEFT: [usize; 80] = [
+    0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15,
+    7, 4, 13, 1, 10, 6, 15, 3, 12, 0, 9, 5, 2, 14, 11, 8,
+    3, 10, 14, 4, 9, 15, 8, 1, 2, 7, 0, 6, 13, 11, 5, 12,
+    1, 9, 11, 10, 0, 8, 12, 4, 13, 3, 7, 15, 14, 5, 6, 2,
+    4, 0, 5, 9, 7, 12, 2, 10, 14, 1, 3, 8, 11, 6, 15, 13,
+];
+
+const R_RIGHT: [usize; 80] = [
+    5, 14, 7, 0, 9, 2, 11, 4, 13, 6, 15, 8, 1, 10, 3, 12,
+    6, 11, 3, 7, 0, 13, 5, 10, 14, 15, 8, 12, 4, 9, 1, 2,
+    15, 5, 1, 3, 7, 14, 6, 9, 11, 8, 12, 2, 10, 0, 4, 13,
+    8, 6, 4, 1, 3, 11, 15, 0, 5, 12, 2, 13, 9, 7, 10, 14,
+    12, 15, 10, 4, 1, 5, 8, 7, 6, 2, 13, 14, 0, 3, 9, 11,
+];
+
+// Rotaciones a la izquierda
+const S_LEFT: [u32; 80] = [
+    11, 14, 15, 12, 5, 8, 7, 9, 11, 13, 14, 15, 6, 7, 9, 8,
+    7, 6, 8, 13, 11, 9, 7, 15, 7, 12, 15, 9, 11, 7, 13, 12,
+    11, 13, 6, 7, 14, 9, 13, 15, 14, 8, 13, 6, 5, 12, 7, 5,
+    11, 12, 14, 15, 14, 15, 9, 8, 9, 14, 5, 6, 8, 6, 5, 12,
+    9, 15, 5, 11, 6, 8, 13, 12, 5, 12, 13, 14, 11, 8, 5, 6,
+];
+
+const S_RIGHT: [u32; 80] = [
+    8, 9, 9, 11, 13, 15, 15, 5, 7, 7, 8, 11, 14, 14, 12, 6,
+    9, 13, 15, 7, 12, 8, 9, 11, 7, 7, 12, 7, 6, 15, 13, 11,
+    9, 7, 15, 11, 8, 6, 6, 14, 12, 13, 5, 14, 13, 13, 7, 5,
+    15, 5, 8, 11, 14, 14, 6, 14, 6, 9, 12, 9, 12, 5, 15, 8,
+    8, 5, 12, 9, 12, 5, 14, 6, 8, 13, 6, 5, 15, 13, 11, 11,
+];
+
+const K_LEFT: [u32; 5] = [0x00000000, 0x5a827999, 0x6ed9eba1, 0x8f1bbcdc, 0xa953fd4e];
+const K_RIGHT: [u32; 5] = [0x50a28be6, 0x5c4dd124, 0x6d703ef3, 0x7a6d76e9, 0x00000000];
+
+#[derive(Clone)]
+pub struct Buffer {
+    data: [u32; 5],
+}
+
+impl Buffer {
+    pub fn from_state(data: [u32; 5]) -> Self {
+        Buffer { data }
+    }
+
+    pub fn new() -> Self {
+        Buffer {
+            data: [
+                0x67452301,
+                0xefcdab89,
+                0x98badcfe,
+                0x10325476,
+                0xc3d2e1f0,
+            ]
+        }
+    }
+
+    pub fn process_block(&mut self, block: &Block512) {
+        let (mut al, mut bl, mut cl, mut dl, mut el) = (self.data[0], self.data[1], self.data[2], self.data[3], self.data[4]);
+        let (mut ar, mut br, mut cr, mut dr, mut er) = (al, bl, cl, dl, el);
+
+        // Las dos líneas recorren las cinco funciones booleanas en sentidos opuestos.
+        for j in 0..80 {
+            let round = j / 16;
+
+            let temp = al
+                .wrapping_add(Buffer::f(round, bl, cl, dl))
+                .wrapping_add(block.get_word(R_LEFT[j]))
+                .wrapping_add(K_LEFT[round])
+                .rotate_left(S_LEFT[j])
+                .wrapping_add(el);
+            al = el;
+            el = dl;
+            dl = cl.rotate_left(10);
+            cl = bl;
+            bl = temp;
+
+            let temp = ar
+                .wrapping_add(Buffer::f(4 - round, br, cr, dr))
+                .wrapping_add(block.get_word(R_RIGHT[j]))
+                .wrapping_add(K_RIGHT[round])
+                .rotate_left(S_RIGHT[j])
+                .wrapping_add(er);
+            ar = er;
+            er = dr;
+            dr = cr.rotate_left(10);
+            cr = br;
+            br = temp;
+        }
+
+        let temp = self.data[1].wrapping_add(cl).wrapping_add(dr);
+        self.data[1] = self.data[2].wrapping_add(dl).wrapping_add(er);
+        self.data[2] = self.data[3].wrapping_add(el).wrapping_add(ar);
+        self.data[3] = self.data[4].wrapping_add(al).wrapping_add(br);
+        self.data[4] = self.data[0].wrapping_add(bl).wrapping_add(cr);
+        self.data[0] = temp;
+    }
+
+    fn f(round: usize, x: u32, y: u32, z: u32) -> u32 {
+        match round {
+            0 => x ^ y ^ z,
+            1 => (x & y) | (!x & z),
+            2 => (x | !y) ^ z,
+            3 => (x & z) | (y & !z),
+            _ => x ^ (y | !z),
+        }
+    }
+
+    pub fn get_digest(&self) -> Output<20> {
+        let mut digest = [0u8; 20];
+        for (chunk, word) in digest.chunks_exact_mut(4).zip(self.data.iter()) {
+            chunk.copy_from_slice(&word.to_le_bytes());
+        }
+        Output::from(digest)
+    }
+
+    pub fn get_hex_digest(&self) -> String {
+        self.get_digest().to_hex()
+    }
+}
+
+impl Default for Buffer {
+    fn default() -> Self {
+        Buffer::new()
+    }
+}
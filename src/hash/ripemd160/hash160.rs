@@ -0,0 +1,23 @@
+use crate::hash::{ripemd160, sha256, Digest, Output};
+
+// HASH160 = RIPEMD160(SHA256(x)), el hash de las direcciones P2PKH/P2WPKH de Bitcoin.
+pub fn hash160(data: &[u8]) -> Output<20> {
+    let inner = sha256::Hasher::digest(data);
+    ripemd160::Hasher::digest(inner.as_bytes())
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hash160_of_generator_pubkey() {
+        let pubkey = [
+            0x02, 0x79, 0xbe, 0x66, 0x7e, 0xf9, 0xdc, 0xbb, 0xac, 0x55, 0xa0, 0x62, 0x95, 0xce, 0x87, 0x0b,
+            0x07, 0x02, 0x9b, 0xfc, 0xdb, 0x2d, 0xce, 0x28, 0xd9, 0x59, 0xf2, 0x81, 0x5b, 0x16, 0xf8, 0x17,
+            0x98,
+        ];
+        assert_eq!(hash160(&pubkey).to_hex(), "751e76e8199196d454941c45d1b3a323f1433bd6");
+    }
+}
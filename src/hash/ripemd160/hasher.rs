@@ -0,0 +1,72 @@
+use crate::hash::padding::{BlockBuffer, LengthEncoding};
+use crate::hash::ripemd160::{Block512, Buffer};
+use crate::hash::{Digest, Output};
+
+/// Incremental RIPEMD-160: feed the message with `update` and pad it in `finalize`.
+#[derive(Clone)]
+pub struct Hasher {
+    buffer: Buffer,
+    blocks: BlockBuffer<64>,
+}
+
+impl Digest for Hasher {
+    type Output = Output<20>;
+
+    const OUTPUT_SIZE: usize = 20;
+    const BLOCK_SIZE: usize = 64;
+
+    fn new() -> Self {
+        Hasher {
+            buffer: Buffer::new(),
+            blocks: BlockBuffer::new(),
+        }
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        let buffer = &mut self.buffer;
+        self.blocks.update(data, |block| buffer.process_block(&Block512::from(block)));
+    }
+
+    fn finalize(mut self) -> Output<20> {
+        let buffer = &mut self.buffer;
+        self.blocks.finalize(LengthEncoding::LittleEndian, |block| buffer.process_block(&Block512::from(block)));
+        self.buffer.get_digest()
+    }
+
+    fn reset(&mut self) {
+        *self = Hasher::new();
+    }
+}
+
+impl Default for Hasher {
+    fn default() -> Self {
+        Hasher::new()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reference_vectors() {
+        assert_eq!(Hasher::digest(b"").to_hex(), "9c1185a5c5e9fc54612808977ee8f548b2258d31");
+        assert_eq!(Hasher::digest(b"abc").to_hex(), "8eb208f7e05d987a9b044a8e98c6b087f15a0bfc");
+        assert_eq!(Hasher::digest(b"message digest").to_hex(), "5d0689ef49d2fae572b881b123a85ffa21595f36");
+        assert_eq!(
+            Hasher::digest(b"12345678901234567890123456789012345678901234567890123456789012345678901234567890").to_hex(),
+            "9b752e45573d4b39f4dbd3323cab82bf63326bfb"
+        );
+    }
+
+    #[test]
+    fn test_million_a() {
+        let mut hasher = Hasher::new();
+        let chunk = [b'a'; 1000];
+        for _ in 0..1000 {
+            hasher.update(&chunk);
+        }
+        assert_eq!(hasher.finalize().to_hex(), "52783243c1697bdbe16d37f97f68f08325dc1528");
+    }
+}
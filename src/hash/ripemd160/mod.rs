@@ -0,0 +1,9 @@
+use crate::hash::md5::block::Block512;
+use buffer::Buffer;
+
+pub mod buffer;
+pub mod hasher;
+pub mod hash160;
+
+pub use hasher::Hasher;
+pub use hash160::hash160;
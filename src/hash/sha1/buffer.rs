@@ -0,0 +1,91 @@
+use crate::hash::sha256::block::Block512;
+use crate::hash::Output;
+
+#[derive(Clone)]
+pub struct Buffer {
+    data: [u32; 5],
+}
+
+impl Buffer {
+    pub fn from_state(data: [u32; 5]) -> Self {
+        Buffer { data }
+    }
+
+    pub fn new() -> Self {
+        Buffer {
+            data: [
+                0x67452301,
+                0xefcdab89,
+                0x98badcfe,
+                0x10325476,
+                0xc3d2e1f0,
+            ]
+        }
+    }
+
+    pub fn process_block(&mut self, block: &Block512) {
+
+        let mut w = [0u32; 80];
+        for (i, word) in w.iter_mut().take(16).enumerate() {
+            *word = block.get_word(i);
+        }
+
+        // Expansión del mensaje
+        for i in 16..80 {
+            w[i] = (w[i - 3] ^ w[i - 8] ^ w[i - 14] ^ w[i - 16]).rotate_left(1);
+        }
+
+        let mut a = self.data[0];
+        let mut b = self.data[1];
+        let mut c = self.data[2];
+        let mut d = self.data[3];
+        let mut e = self.data[4];
+
+        // 80 rondas en cuatro etapas de 20
+        for (i, &w_i) in w.iter().enumerate() {
+            let (f, k) = match i {
+                0..=19 => ((b & c) | (!b & d), 0x5a827999),
+                20..=39 => (b ^ c ^ d, 0x6ed9eba1),
+                40..=59 => ((b & c) | (b & d) | (c & d), 0x8f1bbcdc),
+                _ => (b ^ c ^ d, 0xca62c1d6),
+            };
+
+            let temp = a
+                .rotate_left(5)
+                .wrapping_add(f)
+                .wrapping_add(e)
+                .wrapping_add(k)
+                .wrapping_add(w_i);
+            e = d;
+            d = c;
+            c = b.rotate_left(30);
+            b = a;
+            a = temp;
+        }
+
+        // Suma con los valores iniciales
+        self.data[0] = self.data[0].wrapping_add(a);
+        self.data[1] = self.data[1].wrapping_add(b);
+        self.data[2] = self.data[2].wrapping_add(c);
+        self.data[3] = self.data[3].wrapping_add(d);
+        self.data[4] = self.data[4].wrapping_add(e);
+    }
+
+    pub fn get_digest(&self) -> Output<20> {
+        let mut digest = [0u8; 20];
+        for (chunk, word) in digest.chunks_exact_mut(4).zip(self.data.iter()) {
+            chunk.copy_from_slice(&word.to_be_bytes());
+        }
+        Output::from(digest)
+    }
+
+    pub fn get_hex_digest(&self) -> String {
+        self.get_digest().to_hex()
+    }
+}
+
+impl Default for Buffer {
+    fn default() -> Self {
+        Buffer::new()
+    }
+}
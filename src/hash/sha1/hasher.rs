@@ -0,0 +1,79 @@
+use crate::hash::padding::{BlockBuffer, LengthEncoding};
+use crate::hash::sha1::{Block512, Buffer};
+use crate::hash::{Digest, Output};
+
+/// Incremental SHA-1: feed the message with `update` and pad it in `finalize`.
+#[derive(Clone)]
+pub struct Hasher {
+    buffer: Buffer,
+    blocks: BlockBuffer<64>,
+}
+
+impl Digest for Hasher {
+    type Output = Output<20>;
+
+    const OUTPUT_SIZE: usize = 20;
+    const BLOCK_SIZE: usize = 64;
+
+    fn new() -> Self {
+        Hasher {
+            buffer: Buffer::new(),
+            blocks: BlockBuffer::new(),
+        }
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        let buffer = &mut self.buffer;
+        self.blocks.update(data, |block| buffer.process_block(&Block512::from(block)));
+    }
+
+    fn finalize(mut self) -> Output<20> {
+        let buffer = &mut self.buffer;
+        self.blocks.finalize(LengthEncoding::BigEndian, |block| buffer.process_block(&Block512::from(block)));
+        self.buffer.get_digest()
+    }
+
+    fn reset(&mut self) {
+        *self = Hasher::new();
+    }
+}
+
+impl Default for Hasher {
+    fn default() -> Self {
+        Hasher::new()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fips180_vectors() {
+        assert_eq!(Hasher::digest(b"abc").to_hex(), "a9993e364706816aba3e25717850c26c9cd0d89d");
+        assert_eq!(Hasher::digest(b"").to_hex(), "da39a3ee5e6b4b0d3255bfef95601890afd80709");
+        assert_eq!(
+            Hasher::digest(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq").to_hex(),
+            "84983e441c3bd26ebaae4aa1f95129e5e54670f1"
+        );
+    }
+
+    #[test]
+    fn test_million_a() {
+        let mut hasher = Hasher::new();
+        let chunk = [b'a'; 1000];
+        for _ in 0..1000 {
+            hasher.update(&chunk);
+        }
+        assert_eq!(hasher.finalize().to_hex(), "34aa973cd4c4daa4f61eeb2bdbad27316534016f");
+    }
+
+    #[test]
+    fn test_git_blob_object_id() {
+        let mut hasher = Hasher::new();
+        hasher.update(b"blob 12\0");
+        hasher.update(b"hello world\n");
+        assert_eq!(hasher.finalize().to_hex(), "3b18e512dba79e4c8300dd08aeb37f8e728b8dad");
+    }
+}
@@ -0,0 +1,13 @@
+//! SHA-1 (FIPS 180-4).
+//!
+//! **Legacy only.** SHA-1 is broken for collision resistance (practical
+//! collisions since 2017); use it to verify existing git object IDs, HOTP
+//! codes or old certificates, never for new designs. Prefer `hash::sha256`.
+
+use crate::hash::sha256::block::Block512;
+use buffer::Buffer;
+
+pub mod buffer;
+pub mod hasher;
+
+pub use hasher::Hasher;
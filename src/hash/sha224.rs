@@ -0,0 +1,86 @@
+use crate::hash::padding::{BlockBuffer, LengthEncoding};
+use crate::hash::sha256::block::Block512;
+use crate::hash::sha256::buffer::Buffer;
+use crate::hash::{Digest, Output};
+
+// SHA-224 usa la compresión de SHA-256 con otros valores iniciales y trunca a 224 bits.
+const INITIAL_STATE: [u32; 8] = [
+    0xc1059ed8,
+    0x367cd507,
+    0x3070dd17,
+    0xf70e5939,
+    0xffc00b31,
+    0x68581511,
+    0x64f98fa7,
+    0xbefa4fa4,
+];
+
+#[derive(Clone)]
+pub struct Hasher {
+    buffer: Buffer,
+    blocks: BlockBuffer<64>,
+}
+
+impl Digest for Hasher {
+    type Output = Output<28>;
+
+    const OUTPUT_SIZE: usize = 28;
+    const BLOCK_SIZE: usize = 64;
+
+    fn new() -> Self {
+        Hasher {
+            buffer: Buffer::from_state(INITIAL_STATE),
+            blocks: BlockBuffer::new(),
+        }
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        let buffer = &mut self.buffer;
+        self.blocks.update(data, |block| buffer.process_block(&Block512::from(block)));
+    }
+
+    fn finalize(mut self) -> Output<28> {
+        let buffer = &mut self.buffer;
+        self.blocks.finalize(LengthEncoding::BigEndian, |block| buffer.process_block(&Block512::from(block)));
+
+        let mut digest = [0u8; 28];
+        digest.copy_from_slice(&self.buffer.get_digest().as_bytes()[..28]);
+        Output::from(digest)
+    }
+
+    fn reset(&mut self) {
+        *self = Hasher::new();
+    }
+}
+
+impl Default for Hasher {
+    fn default() -> Self {
+        Hasher::new()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fips180_vectors() {
+        assert_eq!(Hasher::digest(b"abc").to_hex(), "23097d223405d8228642a477bda255b32aadbce4bda0b3f7e36c9da7");
+        assert_eq!(
+            Hasher::digest(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq").to_hex(),
+            "75388b16512776cc5dba5da1fd890150b0c6455cb4f58b1952522525"
+        );
+        assert_eq!(Hasher::digest(b"").to_hex(), "d14a028c2a3a2bc9476102bb288234c415a2b01f828ea62ac5b3e42f");
+    }
+
+    #[test]
+    fn test_million_a() {
+        let mut hasher = Hasher::new();
+        let chunk = [b'a'; 1000];
+        for _ in 0..1000 {
+            hasher.update(&chunk);
+        }
+        assert_eq!(hasher.finalize().to_hex(), "20794655980c91d8bbb4c1ea97618a4bf03f42581948b2ee4ee7ad67");
+    }
+}
@@ -28,20 +28,25 @@ impl TryFrom<RawData> for Vec<Block512> {
     }
 }
 
-impl  Block512{
-    fn new_block(chunk: &[u8]) -> Result<Block512, BlockParseError> {
-        if chunk.len() != 64 {
-            return Err(BlockParseError::InvalidChunkLength(chunk.len()));
-        }
-
+impl From<&[u8; 64]> for Block512 {
+    fn from(chunk: &[u8; 64]) -> Self {
         let mut u32_values = [0u32; 16];
         for (i, u32_value) in u32_values.iter_mut().enumerate() {
             *u32_value = Block512::combine_u8_to_u32(&chunk[i * 4..i * 4 + 4]);
         }
 
-        Ok(Block512 {
+        Block512 {
             data: u32_values,
-        })
+        }
+    }
+}
+
+impl  Block512{
+    fn new_block(chunk: &[u8]) -> Result<Block512, BlockParseError> {
+        let chunk: &[u8; 64] = chunk
+            .try_into()
+            .map_err(|_| BlockParseError::InvalidChunkLength(chunk.len()))?;
+        Ok(Block512::from(chunk))
     }
 
     fn combine_u8_to_u32(bytes: &[u8]) -> u32 {
@@ -74,6 +79,6 @@ mod tests {
         let blocks_result: Result<Vec<Block512>, BlockParseError> = raw.try_into();
         let blocks = blocks_result.unwrap();
         let block = &blocks[0];
-        assert_eq!(block.data,[2153996897, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 24, 0])
+        assert_eq!(block.data,[1633837952, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 24])
     }
 }
\ No newline at end of file
@@ -1,10 +1,20 @@
 use crate::hash::sha256::Block512;
+use crate::hash::Output;
 
+#[derive(Clone)]
 pub struct Buffer {
     data: [u32; 8],
 }
 
 impl Buffer {
+    pub fn from_state(data: [u32; 8]) -> Self {
+        Buffer { data }
+    }
+
+    pub fn state(&self) -> [u32; 8] {
+        self.data
+    }
+
     pub fn new() -> Self {
         Buffer {
             data: [
@@ -110,13 +120,15 @@ impl Buffer {
         x.rotate_right(17) ^ x.rotate_right(19) ^ (x >> 10)
     }
 
-    pub fn get_digest(&self) -> String {
-        let mut digest = String::new();
-
-        for word in &self.data {
-            digest.push_str(&format!("{:08x}", word));
+    pub fn get_digest(&self) -> Output<32> {
+        let mut digest = [0u8; 32];
+        for (chunk, word) in digest.chunks_exact_mut(4).zip(self.data.iter()) {
+            chunk.copy_from_slice(&word.to_be_bytes());
         }
+        Output::from(digest)
+    }
 
-        digest
+    pub fn get_hex_digest(&self) -> String {
+        self.get_digest().to_hex()
     }
 }
@@ -0,0 +1,105 @@
+use crate::hash::sha256::{Block512, Buffer};
+use crate::hash::padding::{BlockBuffer, LengthEncoding};
+use crate::hash::midstate::{Algorithm, Midstate, MidstateError};
+use crate::hash::{Digest, Output};
+
+/// Incremental SHA-256: feed the message with `update` and pad it in `finalize`.
+#[derive(Clone)]
+pub struct Hasher {
+    buffer: Buffer,
+    blocks: BlockBuffer<64>,
+}
+
+impl Digest for Hasher {
+    type Output = Output<32>;
+
+    const OUTPUT_SIZE: usize = 32;
+    const BLOCK_SIZE: usize = 64;
+
+    fn new() -> Self {
+        Hasher {
+            buffer: Buffer::new(),
+            blocks: BlockBuffer::new(),
+        }
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        let buffer = &mut self.buffer;
+        self.blocks.update(data, |block| buffer.process_block(&Block512::from(block)));
+    }
+
+    fn finalize(mut self) -> Output<32> {
+        let buffer = &mut self.buffer;
+        self.blocks.finalize(LengthEncoding::BigEndian, |block| buffer.process_block(&Block512::from(block)));
+        self.buffer.get_digest()
+    }
+
+    fn reset(&mut self) {
+        *self = Hasher::new();
+    }
+}
+
+impl Hasher {
+    pub fn export_midstate(&self) -> Midstate {
+        Midstate::new(Algorithm::Sha256, &self.buffer.state(), self.blocks.len(), self.blocks.pending())
+    }
+
+    pub fn resume(midstate: &Midstate) -> Result<Self, MidstateError> {
+        let state = midstate.state_for::<8>(Algorithm::Sha256)?;
+        Ok(Hasher {
+            buffer: Buffer::from_state(state),
+            blocks: BlockBuffer::resume(midstate.len(), midstate.pending()),
+        })
+    }
+}
+
+impl Default for Hasher {
+    fn default() -> Self {
+        Hasher::new()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sha256_hex(data: &[u8]) -> String {
+        let mut hasher = Hasher::new();
+        hasher.update(data);
+        hasher.finalize().to_hex()
+    }
+
+    #[test]
+    fn test_fips180_vectors() {
+        assert_eq!(sha256_hex(b"abc"), "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad");
+        assert_eq!(
+            sha256_hex(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq"),
+            "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1"
+        );
+    }
+
+    #[test]
+    fn test_million_a() {
+        let mut hasher = Hasher::new();
+        let chunk = [b'a'; 1000];
+        for _ in 0..1000 {
+            hasher.update(&chunk);
+        }
+        assert_eq!(hasher.finalize().to_hex(), "cdc76e5c9914fb9281a1c7e284d73e67f1809a48a497200e046d39ccc7112cd0");
+    }
+
+    #[test]
+    fn test_chunked_updates_match_single_update() {
+        let message: Vec<u8> = (0..1000u32).map(|i| (i % 251) as u8).collect();
+        let expected = sha256_hex(&message);
+
+        for chunk_size in [1, 3, 63, 64, 65, 200] {
+            let mut hasher = Hasher::new();
+            for chunk in message.chunks(chunk_size) {
+                hasher.update(chunk);
+            }
+            assert_eq!(hasher.finalize().to_hex(), expected);
+        }
+    }
+}
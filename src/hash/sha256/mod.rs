@@ -1,11 +1,16 @@
 use block::{Block512, BlockParseError};
 use buffer::Buffer;
 use raw::RawData;
+use crate::hash::Output;
 
 pub mod buffer;
+pub mod hasher;
 pub mod block;
 pub mod raw;
-struct SHA256 {
+
+pub use hasher::Hasher;
+
+pub struct SHA256 {
     blocks: Vec<Block512>,
     buffer: Buffer,
     calculated: bool
@@ -32,21 +37,27 @@ impl SHA256 {
         for block in &self.blocks{
             self.buffer.process_block(block);
         }
+        self.calculated = true;
     }
 
-    pub fn get_digest(& mut self) -> String{
+    pub fn get_digest(& mut self) -> Output<32>{
         if !self.calculated {
             self.calculate_digest();
         }
         self.buffer.get_digest()
     }
 
+    pub fn get_hex_digest(& mut self) -> String{
+        self.get_digest().to_hex()
+    }
+
 }
 
 
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
+    use crate::hash::Digest;
 
     use super::*;
 
@@ -55,7 +66,7 @@ mod tests {
         let raw_data = RawData::from_str(String::from("hello world").as_str()).expect("Conversion failed");
         let mut sha256 = SHA256::new(raw_data).expect("Failed to create SHA256 instance");
         // Calculate digest
-        let digest = sha256.get_digest();
+        let digest = sha256.get_hex_digest();
         // Compare the result with the expected digest
         assert_eq!(digest, "b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9");
     }
@@ -65,7 +76,7 @@ mod tests {
         let raw_data = RawData::from_str(String::from("A simple message").as_str()).expect("Conversion failed");
         let mut sha256 = SHA256::new(raw_data).expect("Failed to create SHA256 instance");
         // Calculate digest
-        let digest = sha256.get_digest();
+        let digest = sha256.get_hex_digest();
         // Compare the result with the expected digest
         assert_eq!(digest, "9a741f76c050657815eadefc360e8cd191edd49f66ecbdedd048a364d8ef5e86");
     }
@@ -75,7 +86,7 @@ mod tests {
         let raw_data = RawData::from_str(String::from("").as_str()).expect("Conversion failed");
         let mut sha256 = SHA256::new(raw_data).expect("Failed to create SHA256 instance");
         // Calculate digest
-        let digest = sha256.get_digest();
+        let digest = sha256.get_hex_digest();
         // Compare the result with the expected digest
         assert_eq!(digest, "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855");
     }
@@ -85,9 +96,15 @@ mod tests {
         let raw_data = RawData::from_str(String::from("Hundreds of companies around the world are using Rust in production today for fast, low-resource, cross-platform solutions. Software you know and love, like Firefox, Dropbox, and Cloudflare, uses Rust. From startups to large corporations, from embedded devices to scalable web services, Rust is a great fit.").as_str()).expect("Conversion failed");
         let mut sha256 = SHA256::new(raw_data).expect("Failed to create SHA256 instance");
         // Calculate digest
-        let digest = sha256.get_digest();
+        let digest = sha256.get_hex_digest();
         // Compare the result with the expected digest
         assert_eq!(digest, "dc4340b9b80725c74dbac509869131f2b17b4f5735ac03eef286f5ba1e652dfe");
     }
 
-}
\ No newline at end of file
+    #[test]
+    fn test_sha256_binary_multi_block() {
+        let bytes: Vec<u8> = (0..=255).collect();
+        let mut sha256 = SHA256::new(RawData::from(bytes.as_slice())).expect("Failed to create SHA256 instance");
+        assert_eq!(sha256.get_digest(), Hasher::digest(&bytes));
+    }
+}
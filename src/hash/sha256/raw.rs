@@ -1,4 +1,5 @@
-use std::{slice::Chunks, str::FromStr};
+use std::{io::{self, Read}, slice::Chunks, str::FromStr};
+use crate::hash::padding::{pad_message, LengthEncoding};
 
 pub struct RawData{
     data: Vec<u8>
@@ -8,15 +9,21 @@ impl FromStr for RawData {
     type Err = String;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut bytes = utf8_to_vec_u8(s);
-        let original_length_bits = (bytes.len() as u64) * 8;
-        bytes.push(0x80);
-        while (bytes.len() * 8) % 512 != 448 {
-            bytes.push(0x00);
+        Ok(RawData::from(utf8_to_vec_u8(s)))
+    }
+}
+
+impl From<Vec<u8>> for RawData {
+    fn from(bytes: Vec<u8>) -> Self {
+        RawData {
+            data: pad_message::<64>(bytes, LengthEncoding::BigEndian),
         }
-        bytes.extend_from_slice(&original_length_bits.to_be_bytes());
-        let raw_data = RawData { data: bytes };
-        Ok(raw_data)
+    }
+}
+
+impl From<&[u8]> for RawData {
+    fn from(bytes: &[u8]) -> Self {
+        RawData::from(bytes.to_vec())
     }
 }
 
@@ -31,7 +38,13 @@ fn utf8_to_vec_u8(s: &str) -> Vec<u8> {
 }
 
 impl RawData {
-    
+
+    pub fn from_reader<R: Read>(mut reader: R) -> io::Result<Self> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes)?;
+        Ok(RawData::from(bytes))
+    }
+
     pub fn len(&self) -> usize {
         self.data.len()
     }
@@ -65,7 +78,7 @@ mod tests {
             0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
             0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
             0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x18, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00 
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x18 
         ];
 
         let raw_data = RawData::from_str(input).expect("Conversion failed");
@@ -94,11 +107,33 @@ mod tests {
         let input = "a".repeat(55);  // 55 bytes * 8 = 440 bits
         let mut expected_hex: Vec<u8> = input.bytes().collect();
         expected_hex.push(0x80);
-        expected_hex.extend(vec![184,1,0,0,0,0,0,0]);
+        expected_hex.extend(vec![0,0,0,0,0,0,1,184]);
         let raw_data = RawData::from_str(&input).expect("Conversion failed");
         assert_eq!(raw_data.data, expected_hex);
     }
 
+    #[test]
+    fn test_from_binary_bytes() {
+        let input: &[u8] = &[0xff, 0x00, 0xfe];
+        let raw_data = RawData::from(input);
+        assert_eq!(raw_data.len(), 64);
+        assert_eq!(raw_data.data[..4], [0xff, 0x00, 0xfe, 0x80]);
+    }
+
+    #[test]
+    fn test_448_bit_boundary_spills_into_second_block() {
+        let input = vec![0x61; 56];  // 56 bytes * 8 = 448 bits
+        let raw_data = RawData::from(input);
+        assert_eq!(raw_data.len(), 128);
+        assert_eq!(raw_data.data[56], 0x80);
+        assert!(raw_data.data[57..120].iter().all(|&byte| byte == 0));
+    }
 
-    
+    #[test]
+    fn test_from_reader_matches_from_bytes() {
+        let input: Vec<u8> = (0..=255).collect();
+        let raw_data = RawData::from_reader(input.as_slice()).expect("Read failed");
+        assert_eq!(raw_data.data, RawData::from(input).data);
+        assert_eq!(raw_data.len() % 64, 0);
+    }
 }
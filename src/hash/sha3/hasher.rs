@@ -0,0 +1,94 @@
+use crate::hash::sha3::Sponge;
+use crate::hash::{Digest, Output};
+
+const SHA3_SUFFIX: u8 = 0x06;
+
+// La capacidad es el doble de la salida, así que la tasa es 200 - 2N bytes.
+#[derive(Clone)]
+pub struct Sha3<const N: usize> {
+    sponge: Sponge,
+}
+
+pub type Sha3_224 = Sha3<28>;
+pub type Sha3_256 = Sha3<32>;
+pub type Sha3_384 = Sha3<48>;
+pub type Sha3_512 = Sha3<64>;
+
+impl<const N: usize> Digest for Sha3<N> {
+    type Output = Output<N>;
+
+    const OUTPUT_SIZE: usize = N;
+    const BLOCK_SIZE: usize = 200 - 2 * N;
+
+    fn new() -> Self {
+        Sha3 {
+            sponge: Sponge::new(Self::BLOCK_SIZE, SHA3_SUFFIX),
+        }
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        self.sponge.absorb(data);
+    }
+
+    fn finalize(mut self) -> Output<N> {
+        let mut digest = [0u8; N];
+        self.sponge.pad();
+        self.sponge.squeeze(&mut digest);
+        Output::from(digest)
+    }
+
+    fn reset(&mut self) {
+        self.sponge.reset();
+    }
+}
+
+impl<const N: usize> Default for Sha3<N> {
+    fn default() -> Self {
+        Sha3::new()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fips202_vectors() {
+        assert_eq!(Sha3_224::digest(b"").to_hex(), "6b4e03423667dbb73b6e15454f0eb1abd4597f9a1b078e3f5b5a6bc7");
+        assert_eq!(Sha3_256::digest(b"").to_hex(), "a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a");
+        assert_eq!(Sha3_224::digest(b"abc").to_hex(), "e642824c3f8cf24ad09234ee7d3c766fc9a3a5168d0c94ad73b46fdf");
+        assert_eq!(Sha3_256::digest(b"abc").to_hex(), "3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532");
+        assert_eq!(
+            Sha3_384::digest(b"abc").to_hex(),
+            "ec01498288516fc926459f58e2c6ad8df9b473cb0fc08c2596da7cf0e49be4b298d88cea927ac7f539f1edf228376d25"
+        );
+        assert_eq!(
+            Sha3_512::digest(b"abc").to_hex(),
+            "b751850b1a57168a5693cd924b6b096e08f621827444f70d884f5d0240d2712e10e116e9192af3c91a7ec57647e3934057340b4cf408d5a56592f8274eec53f0"
+        );
+    }
+
+    #[test]
+    fn test_multi_block_messages() {
+        assert_eq!(
+            Sha3_256::digest(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq").to_hex(),
+            "41c0dba2a9d6240849100376a8235e2c82e1b9998a999e21db32dd97496d3376"
+        );
+
+        let mut hasher = Sha3_256::new();
+        let chunk = [b'a'; 1000];
+        for _ in 0..1000 {
+            hasher.update(&chunk);
+        }
+        assert_eq!(hasher.finalize().to_hex(), "5c8875ae474a3634ba4fd55ec85bffd661f32aca75c6d699d0cdcb6c115891c1");
+    }
+
+    #[test]
+    fn test_rates() {
+        assert_eq!(Sha3_224::BLOCK_SIZE, 144);
+        assert_eq!(Sha3_256::BLOCK_SIZE, 136);
+        assert_eq!(Sha3_384::BLOCK_SIZE, 104);
+        assert_eq!(Sha3_512::BLOCK_SIZE, 72);
+    }
+}
@@ -0,0 +1,8 @@
+use keccak::Sponge;
+
+pub mod keccak;
+pub mod hasher;
+pub mod shake;
+
+pub use hasher::{Sha3, Sha3_224, Sha3_256, Sha3_384, Sha3_512};
+pub use shake::{Shake, Shake128, Shake256, XofReader};
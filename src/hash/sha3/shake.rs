@@ -0,0 +1,120 @@
+use std::io::{self, Read};
+
+use crate::hash::sha3::Sponge;
+
+const SHAKE_SUFFIX: u8 = 0x1f;
+
+// C es el nivel de seguridad en bytes: SHAKE128 usa 16, SHAKE256 usa 32.
+#[derive(Clone)]
+pub struct Shake<const C: usize> {
+    sponge: Sponge,
+}
+
+pub type Shake128 = Shake<16>;
+pub type Shake256 = Shake<32>;
+
+impl<const C: usize> Shake<C> {
+    pub fn new() -> Self {
+        Shake {
+            sponge: Sponge::new(200 - 2 * C, SHAKE_SUFFIX),
+        }
+    }
+
+    pub fn update(&mut self, data: &[u8]) {
+        self.sponge.absorb(data);
+    }
+
+    pub fn finalize_xof(mut self) -> XofReader {
+        self.sponge.pad();
+        XofReader { sponge: self.sponge }
+    }
+
+    pub fn reset(&mut self) {
+        self.sponge.reset();
+    }
+
+    pub fn digest(data: &[u8], output_len: usize) -> Vec<u8> {
+        let mut shake = Self::new();
+        shake.update(data);
+        shake.finalize_xof().read_vec(output_len)
+    }
+}
+
+impl<const C: usize> Default for Shake<C> {
+    fn default() -> Self {
+        Shake::new()
+    }
+}
+
+pub struct XofReader {
+    sponge: Sponge,
+}
+
+impl XofReader {
+    pub fn squeeze(&mut self, output: &mut [u8]) {
+        self.sponge.squeeze(output);
+    }
+
+    pub fn read_vec(&mut self, len: usize) -> Vec<u8> {
+        let mut output = vec![0u8; len];
+        self.squeeze(&mut output);
+        output
+    }
+}
+
+impl Read for XofReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.squeeze(buf);
+        Ok(buf.len())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+    }
+
+    #[test]
+    fn test_fips202_vectors() {
+        assert_eq!(
+            hex(&Shake128::digest(b"", 32)),
+            "7f9c2ba4e88f827d616045507605853ed73b8093f6efbc88eb1a6eacfa66ef26"
+        );
+        assert_eq!(
+            hex(&Shake128::digest(b"abc", 32)),
+            "5881092dd818bf5cf8a3ddb793fbcba74097d5c526a6d35f97b83351940f2cc8"
+        );
+        assert_eq!(
+            hex(&Shake256::digest(b"", 64)),
+            "46b9dd2b0ba88d13233b3feb743eeb243fcd52ea62b81b82b50c27646ed5762fd75dc4ddd8c0f200cb05019d67b592f6fc821c49479ab48640292eacb3b7c4be"
+        );
+    }
+
+    #[test]
+    fn test_incremental_squeeze_matches_single_read() {
+        let expected = Shake256::digest(b"squeeze me", 500);
+
+        let mut shake = Shake256::new();
+        shake.update(b"squeeze ");
+        shake.update(b"me");
+        let mut reader = shake.finalize_xof();
+        let mut output = Vec::new();
+        for len in [1, 135, 136, 228] {
+            output.extend(reader.read_vec(len));
+        }
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_io_read() {
+        let mut shake = Shake128::new();
+        shake.update(b"abc");
+        let mut output = [0u8; 32];
+        shake.finalize_xof().read_exact(&mut output).unwrap();
+        assert_eq!(output.to_vec(), Shake128::digest(b"abc", 32));
+    }
+}
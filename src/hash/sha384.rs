@@ -0,0 +1,68 @@
+use crate::hash::sha512;
+use crate::hash::{Digest, Output};
+
+// SHA-384 usa la compresión de SHA-512 con otros valores iniciales y trunca a 384 bits.
+const INITIAL_STATE: [u64; 8] = [
+    0xcbbb9d5dc1059ed8,
+    0x629a292a367cd507,
+    0x9159015a3070dd17,
+    0x152fecd8f70e5939,
+    0x67332667ffc00b31,
+    0x8eb44a8768581511,
+    0xdb0c2e0d64f98fa7,
+    0x47b5481dbefa4fa4,
+];
+
+#[derive(Clone)]
+pub struct Hasher {
+    inner: sha512::Hasher,
+}
+
+impl Digest for Hasher {
+    type Output = Output<48>;
+
+    const OUTPUT_SIZE: usize = 48;
+    const BLOCK_SIZE: usize = 128;
+
+    fn new() -> Self {
+        Hasher {
+            inner: sha512::Hasher::from_state(INITIAL_STATE),
+        }
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        self.inner.update(data);
+    }
+
+    fn finalize(self) -> Output<48> {
+        self.inner.finalize_truncated()
+    }
+
+    fn reset(&mut self) {
+        *self = Hasher::new();
+    }
+}
+
+impl Default for Hasher {
+    fn default() -> Self {
+        Hasher::new()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fips180_vectors() {
+        assert_eq!(
+            Hasher::digest(b"abc").to_hex(),
+            "cb00753f45a35e8bb5a03d699ac65007272c32ab0eded1631a8b605a43ff5bed8086072ba1e7cc2358baeca134c825a7"
+        );
+        assert_eq!(
+            Hasher::digest(b"abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmnoijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu").to_hex(),
+            "09330c33f71147e83d192fc782cd1b4753111b173b3b05d22fa08086e3b0f712fcc7c71a557e2db966c3e9fa91746039"
+        );
+    }
+}
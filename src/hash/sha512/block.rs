@@ -0,0 +1,44 @@
+#[derive(Debug)]
+pub struct Block1024{
+    data:[u64; 16]
+}
+
+impl From<&[u8; 128]> for Block1024 {
+    fn from(chunk: &[u8; 128]) -> Self {
+        let mut u64_values = [0u64; 16];
+        for (i, u64_value) in u64_values.iter_mut().enumerate() {
+            *u64_value = Block1024::combine_u8_to_u64(&chunk[i * 8..i * 8 + 8]);
+        }
+
+        Block1024 {
+            data: u64_values,
+        }
+    }
+}
+
+impl Block1024{
+    fn combine_u8_to_u64(bytes: &[u8]) -> u64 {
+        bytes.iter().fold(0u64, |word, &byte| (word << 8) | byte as u64)
+    }
+
+    pub fn get_word(&self, idx: usize) -> u64{
+        self.data[idx]
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_words_are_big_endian(){
+        let mut chunk = [0u8; 128];
+        chunk[..3].copy_from_slice(b"abc");
+        chunk[3] = 0x80;
+        chunk[127] = 0x18;
+        let block = Block1024::from(&chunk);
+        assert_eq!(block.get_word(0), 0x6162638000000000);
+        assert_eq!(block.get_word(15), 0x18);
+    }
+}
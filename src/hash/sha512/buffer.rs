@@ -0,0 +1,148 @@
+use crate::hash::sha512::Block1024;
+use crate::hash::Output;
+
+#[derive(Clone)]
+pub struct Buffer {
+    data: [u64; 8],
+}
+
+impl Buffer {
+    pub fn from_state(data: [u64; 8]) -> Self {
+        Buffer { data }
+    }
+
+    pub fn new() -> Self {
+        Buffer {
+            data: [
+                0x6a09e667f3bcc908,
+                0xbb67ae8584caa73b,
+                0x3c6ef372fe94f82b,
+                0xa54ff53a5f1d36f1,
+                0x510e527fade682d1,
+                0x9b05688c2b3e6c1f,
+                0x1f83d9abfb41bd6b,
+                0x5be0cd19137e2179,
+            ]
+        }
+    }
+
+    pub fn process_block(&mut self, block: &Block1024) {
+
+        let mut w = [0u64; 80];
+        for (i, word) in w.iter_mut().take(16).enumerate() {
+            *word = block.get_word(i);
+        }
+
+        // Expansión del mensaje
+        for i in 16..80 {
+            w[i] = Buffer::small_sigma1(w[i - 2])
+                .wrapping_add(w[i - 7])
+                .wrapping_add(Buffer::small_sigma0(w[i - 15]))
+                .wrapping_add(w[i - 16]);
+        }
+
+        let mut a = self.data[0];
+        let mut b = self.data[1];
+        let mut c = self.data[2];
+        let mut d = self.data[3];
+        let mut e = self.data[4];
+        let mut f = self.data[5];
+        let mut g = self.data[6];
+        let mut h = self.data[7];
+
+        // Constantes de SHA-512
+        let k: [u64; 80] = [
+            0x428a2f98d728ae22, 0x7137449123ef65cd, 0xb5c0fbcfec4d3b2f, 0xe9b5dba58189dbbc,
+            0x3956c25bf348b538, 0x59f111f1b605d019, 0x923f82a4af194f9b, 0xab1c5ed5da6d8118,
+            0xd807aa98a3030242, 0x12835b0145706fbe, 0x243185be4ee4b28c, 0x550c7dc3d5ffb4e2,
+            0x72be5d74f27b896f, 0x80deb1fe3b1696b1, 0x9bdc06a725c71235, 0xc19bf174cf692694,
+            0xe49b69c19ef14ad2, 0xefbe4786384f25e3, 0x0fc19dc68b8cd5b5, 0x240ca1cc77ac9c65,
+            0x2de92c6f592b0275, 0x4a7484aa6ea6e483, 0x5cb0a9dcbd41fbd4, 0x76f988da831153b5,
+            0x983e5152ee66dfab, 0xa831c66d2db43210, 0xb00327c898fb213f, 0xbf597fc7beef0ee4,
+            0xc6e00bf33da88fc2, 0xd5a79147930aa725, 0x06ca6351e003826f, 0x142929670a0e6e70,
+            0x27b70a8546d22ffc, 0x2e1b21385c26c926, 0x4d2c6dfc5ac42aed, 0x53380d139d95b3df,
+            0x650a73548baf63de, 0x766a0abb3c77b2a8, 0x81c2c92e47edaee6, 0x92722c851482353b,
+            0xa2bfe8a14cf10364, 0xa81a664bbc423001, 0xc24b8b70d0f89791, 0xc76c51a30654be30,
+            0xd192e819d6ef5218, 0xd69906245565a910, 0xf40e35855771202a, 0x106aa07032bbd1b8,
+            0x19a4c116b8d2d0c8, 0x1e376c085141ab53, 0x2748774cdf8eeb99, 0x34b0bcb5e19b48a8,
+            0x391c0cb3c5c95a63, 0x4ed8aa4ae3418acb, 0x5b9cca4f7763e373, 0x682e6ff3d6b2b8a3,
+            0x748f82ee5defb2fc, 0x78a5636f43172f60, 0x84c87814a1f0ab72, 0x8cc702081a6439ec,
+            0x90befffa23631e28, 0xa4506cebde82bde9, 0xbef9a3f7b2c67915, 0xc67178f2e372532b,
+            0xca273eceea26619c, 0xd186b8c721c0c207, 0xeada7dd6cde0eb1e, 0xf57d4f7fee6ed178,
+            0x06f067aa72176fba, 0x0a637dc5a2c898a6, 0x113f9804bef90dae, 0x1b710b35131c471b,
+            0x28db77f523047d84, 0x32caab7b40c72493, 0x3c9ebe0a15c9bebc, 0x431d67c49c100d4c,
+            0x4cc5d4becb3e42b6, 0x597f299cfc657e2a, 0x5fcb6fab3ad6faec, 0x6c44198c4a475817,
+        ];
+
+        // 80 rondas de compresión
+        for (&k_i, &w_i) in k.iter().zip(w.iter()) {
+            let temp1 = h
+                .wrapping_add(Buffer::big_sigma1(e))
+                .wrapping_add(Buffer::choice(e, f, g))
+                .wrapping_add(k_i)
+                .wrapping_add(w_i);
+            let temp2 = Buffer::big_sigma0(a).wrapping_add(Buffer::majority(a, b, c));
+            h = g;
+            g = f;
+            f = e;
+            e = d.wrapping_add(temp1);
+            d = c;
+            c = b;
+            b = a;
+            a = temp1.wrapping_add(temp2);
+        }
+
+        // Suma con los valores iniciales
+        self.data[0] = self.data[0].wrapping_add(a);
+        self.data[1] = self.data[1].wrapping_add(b);
+        self.data[2] = self.data[2].wrapping_add(c);
+        self.data[3] = self.data[3].wrapping_add(d);
+        self.data[4] = self.data[4].wrapping_add(e);
+        self.data[5] = self.data[5].wrapping_add(f);
+        self.data[6] = self.data[6].wrapping_add(g);
+        self.data[7] = self.data[7].wrapping_add(h);
+    }
+
+    // Helper functions
+    fn choice(x: u64, y: u64, z: u64) -> u64 {
+        (x & y) ^ (!x & z)
+    }
+
+    fn majority(x: u64, y: u64, z: u64) -> u64 {
+        (x & y) ^ (x & z) ^ (y & z)
+    }
+
+    fn big_sigma0(x: u64) -> u64 {
+        x.rotate_right(28) ^ x.rotate_right(34) ^ x.rotate_right(39)
+    }
+
+    fn big_sigma1(x: u64) -> u64 {
+        x.rotate_right(14) ^ x.rotate_right(18) ^ x.rotate_right(41)
+    }
+
+    fn small_sigma0(x: u64) -> u64 {
+        x.rotate_right(1) ^ x.rotate_right(8) ^ (x >> 7)
+    }
+
+    fn small_sigma1(x: u64) -> u64 {
+        x.rotate_right(19) ^ x.rotate_right(61) ^ (x >> 6)
+    }
+
+    pub fn get_digest(&self) -> Output<64> {
+        let mut digest = [0u8; 64];
+        for (chunk, word) in digest.chunks_exact_mut(8).zip(self.data.iter()) {
+            chunk.copy_from_slice(&word.to_be_bytes());
+        }
+        Output::from(digest)
+    }
+
+    pub fn get_hex_digest(&self) -> String {
+        self.get_digest().to_hex()
+    }
+}
+
+impl Default for Buffer {
+    fn default() -> Self {
+        Buffer::new()
+    }
+}
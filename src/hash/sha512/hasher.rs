@@ -0,0 +1,97 @@
+use crate::hash::padding::{BlockBuffer, LengthEncoding};
+use crate::hash::sha512::{Block1024, Buffer};
+use crate::hash::{Digest, Output};
+
+/// Incremental SHA-512: feed the message with `update` and pad it in `finalize`.
+#[derive(Clone)]
+pub struct Hasher {
+    buffer: Buffer,
+    blocks: BlockBuffer<128>,
+}
+
+impl Hasher {
+    // Punto de partida compartido con SHA-384 y SHA-512/t, que solo cambian el IV.
+    pub(crate) fn from_state(state: [u64; 8]) -> Self {
+        Hasher {
+            buffer: Buffer::from_state(state),
+            blocks: BlockBuffer::new(),
+        }
+    }
+
+    pub(crate) fn finalize_truncated<const N: usize>(self) -> Output<N> {
+        let mut digest = [0u8; N];
+        digest.copy_from_slice(&self.finalize().as_bytes()[..N]);
+        Output::from(digest)
+    }
+}
+
+impl Digest for Hasher {
+    type Output = Output<64>;
+
+    const OUTPUT_SIZE: usize = 64;
+    const BLOCK_SIZE: usize = 128;
+
+    fn new() -> Self {
+        Hasher {
+            buffer: Buffer::new(),
+            blocks: BlockBuffer::new(),
+        }
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        let buffer = &mut self.buffer;
+        self.blocks.update(data, |block| buffer.process_block(&Block1024::from(block)));
+    }
+
+    fn finalize(mut self) -> Output<64> {
+        let buffer = &mut self.buffer;
+        self.blocks.finalize(LengthEncoding::BigEndian, |block| buffer.process_block(&Block1024::from(block)));
+        self.buffer.get_digest()
+    }
+
+    fn reset(&mut self) {
+        *self = Hasher::new();
+    }
+}
+
+impl Default for Hasher {
+    fn default() -> Self {
+        Hasher::new()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fips180_vectors() {
+        assert_eq!(
+            Hasher::digest(b"abc").to_hex(),
+            "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f"
+        );
+        assert_eq!(
+            Hasher::digest(b"abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmnoijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu").to_hex(),
+            "8e959b75dae313da8cf4f72814fc143f8f7779c6eb9f7fa17299aeadb6889018501d289e4900f7e4331b99dec4b5433ac7d329eeb6dd26545e96e55b874be909"
+        );
+        assert_eq!(
+            Hasher::digest(b"").to_hex(),
+            "cf83e1357eefb8bdf1542850d66d8007d620e4050b5715dc83f4a921d36ce9ce47d0d13c5d85f2b0ff8318d2877eec2f63b931bd47417a81a538327af927da3e"
+        );
+    }
+
+    #[test]
+    fn test_chunked_updates_match_single_update() {
+        let message: Vec<u8> = (0..1000u32).map(|i| (i % 251) as u8).collect();
+        let expected = Hasher::digest(&message);
+
+        for chunk_size in [1, 7, 127, 128, 129, 300] {
+            let mut hasher = Hasher::new();
+            for chunk in message.chunks(chunk_size) {
+                hasher.update(chunk);
+            }
+            assert_eq!(hasher.finalize(), expected);
+        }
+    }
+}
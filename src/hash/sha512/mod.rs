@@ -0,0 +1,8 @@
+use block::Block1024;
+use buffer::Buffer;
+
+pub mod buffer;
+pub mod hasher;
+pub mod block;
+
+pub use hasher::Hasher;
@@ -0,0 +1,68 @@
+use crate::hash::sha512;
+use crate::hash::{Digest, Output};
+
+// SHA-512/224: IV generado según FIPS 180-4 §5.3.6 y salida truncada a 224 bits.
+const INITIAL_STATE: [u64; 8] = [
+    0x8c3d37c819544da2,
+    0x73e1996689dcd4d6,
+    0x1dfab7ae32ff9c82,
+    0x679dd514582f9fcf,
+    0x0f6d2b697bd44da8,
+    0x77e36f7304c48942,
+    0x3f9d85a86a1d36c8,
+    0x1112e6ad91d692a1,
+];
+
+#[derive(Clone)]
+pub struct Hasher {
+    inner: sha512::Hasher,
+}
+
+impl Digest for Hasher {
+    type Output = Output<28>;
+
+    const OUTPUT_SIZE: usize = 28;
+    const BLOCK_SIZE: usize = 128;
+
+    fn new() -> Self {
+        Hasher {
+            inner: sha512::Hasher::from_state(INITIAL_STATE),
+        }
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        self.inner.update(data);
+    }
+
+    fn finalize(self) -> Output<28> {
+        self.inner.finalize_truncated()
+    }
+
+    fn reset(&mut self) {
+        *self = Hasher::new();
+    }
+}
+
+impl Default for Hasher {
+    fn default() -> Self {
+        Hasher::new()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fips180_vectors() {
+        assert_eq!(
+            Hasher::digest(b"abc").to_hex(),
+            "4634270f707b6a54daae7530460842e20e37ed265ceee9a43e8924aa"
+        );
+        assert_eq!(
+            Hasher::digest(b"abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmnoijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu").to_hex(),
+            "23fec5bb94d60b23308192640b0c453335d664734fe40e7268674af9"
+        );
+    }
+}
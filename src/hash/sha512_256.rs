@@ -0,0 +1,68 @@
+use crate::hash::sha512;
+use crate::hash::{Digest, Output};
+
+// SHA-512/256: IV generado según FIPS 180-4 §5.3.6 y salida truncada a 256 bits.
+const INITIAL_STATE: [u64; 8] = [
+    0x22312194fc2bf72c,
+    0x9f555fa3c84c64c2,
+    0x2393b86b6f53b151,
+    0x963877195940eabd,
+    0x96283ee2a88effe3,
+    0xbe5e1e2553863992,
+    0x2b0199fc2c85b8aa,
+    0x0eb72ddc81c52ca2,
+];
+
+#[derive(Clone)]
+pub struct Hasher {
+    inner: sha512::Hasher,
+}
+
+impl Digest for Hasher {
+    type Output = Output<32>;
+
+    const OUTPUT_SIZE: usize = 32;
+    const BLOCK_SIZE: usize = 128;
+
+    fn new() -> Self {
+        Hasher {
+            inner: sha512::Hasher::from_state(INITIAL_STATE),
+        }
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        self.inner.update(data);
+    }
+
+    fn finalize(self) -> Output<32> {
+        self.inner.finalize_truncated()
+    }
+
+    fn reset(&mut self) {
+        *self = Hasher::new();
+    }
+}
+
+impl Default for Hasher {
+    fn default() -> Self {
+        Hasher::new()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fips180_vectors() {
+        assert_eq!(
+            Hasher::digest(b"abc").to_hex(),
+            "53048e2681941ef99b2e29b76b4c7dabe4c2d0c634fc6d46e0e2f13107e7af23"
+        );
+        assert_eq!(
+            Hasher::digest(b"abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmnoijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu").to_hex(),
+            "3928e184fb8690f840da3988121d31be65cb9d3ef83ee6146feac861e19b563a"
+        );
+    }
+}
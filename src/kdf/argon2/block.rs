@@ -0,0 +1,97 @@
+// Bloque de memoria de Argon2: 1024 bytes vistos como 128 palabras de 64 bits.
+pub const BLOCK_WORDS: usize = 128;
+pub const BLOCK_BYTES: usize = BLOCK_WORDS * 8;
+
+#[derive(Clone, Copy)]
+pub struct Block([u64; BLOCK_WORDS]);
+
+impl Block {
+    pub fn zero() -> Self {
+        Block([0u64; BLOCK_WORDS])
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        let mut block = Block::zero();
+        for (word, chunk) in block.0.iter_mut().zip(bytes.chunks_exact(8)) {
+            *word = u64::from_le_bytes(chunk.try_into().unwrap());
+        }
+        block
+    }
+
+    pub fn to_bytes(&self) -> [u8; BLOCK_BYTES] {
+        let mut bytes = [0u8; BLOCK_BYTES];
+        for (chunk, word) in bytes.chunks_exact_mut(8).zip(self.0.iter()) {
+            chunk.copy_from_slice(&word.to_le_bytes());
+        }
+        bytes
+    }
+
+    pub fn word(&self, index: usize) -> u64 {
+        self.0[index]
+    }
+
+    pub fn set_word(&mut self, index: usize, value: u64) {
+        self.0[index] = value;
+    }
+
+    pub fn xor_with(&mut self, other: &Block) {
+        for (word, other_word) in self.0.iter_mut().zip(other.0.iter()) {
+            *word ^= other_word;
+        }
+    }
+
+    // G(X, Y): P sobre las 8 filas y luego sobre las 8 columnas de R = X ^ Y, y se suma R de nuevo.
+    pub fn compress(x: &Block, y: &Block) -> Block {
+        let mut r = *x;
+        r.xor_with(y);
+        let mut q = r;
+
+        for row in 0..8 {
+            let mut v = [0u64; 16];
+            v.copy_from_slice(&q.0[row * 16..row * 16 + 16]);
+            permute(&mut v);
+            q.0[row * 16..row * 16 + 16].copy_from_slice(&v);
+        }
+
+        for column in 0..8 {
+            let mut v = [0u64; 16];
+            for register in 0..8 {
+                v[2 * register] = q.0[2 * column + 16 * register];
+                v[2 * register + 1] = q.0[2 * column + 16 * register + 1];
+            }
+            permute(&mut v);
+            for register in 0..8 {
+                q.0[2 * column + 16 * register] = v[2 * register];
+                q.0[2 * column + 16 * register + 1] = v[2 * register + 1];
+            }
+        }
+
+        q.xor_with(&r);
+        q
+    }
+}
+
+// Función de mezcla de BLAKE2b con el producto de las mitades bajas para endurecerla.
+fn gb(v: &mut [u64; 16], a: usize, b: usize, c: usize, d: usize) {
+    let mul = |x: u64, y: u64| 2u64.wrapping_mul(x & 0xffffffff).wrapping_mul(y & 0xffffffff);
+
+    v[a] = v[a].wrapping_add(v[b]).wrapping_add(mul(v[a], v[b]));
+    v[d] = (v[d] ^ v[a]).rotate_right(32);
+    v[c] = v[c].wrapping_add(v[d]).wrapping_add(mul(v[c], v[d]));
+    v[b] = (v[b] ^ v[c]).rotate_right(24);
+    v[a] = v[a].wrapping_add(v[b]).wrapping_add(mul(v[a], v[b]));
+    v[d] = (v[d] ^ v[a]).rotate_right(16);
+    v[c] = v[c].wrapping_add(v[d]).wrapping_add(mul(v[c], v[d]));
+    v[b] = (v[b] ^ v[c]).rotate_right(63);
+}
+
+fn permute(v: &mut [u64; 16]) {
+    gb(v, 0, 4, 8, 12);
+    gb(v, 1, 5, 9, 13);
+    gb(v, 2, 6, 10, 14);
+    gb(v, 3, 7, 11, 15);
+    gb(v, 0, 5, 10, 15);
+    gb(v, 1, 6, 11, 12);
+    gb(v, 2, 7, 8, 13);
+    gb(v, 3, 4, 9, 14);
+}
@@ -0,0 +1,393 @@
+pub mod block;
+pub mod phc;
+
+use crate::hash::blake2::Blake2b;
+use block::{Block, BLOCK_BYTES, BLOCK_WORDS};
+
+pub use phc::verify_password;
+
+const VERSION: u32 = 0x13;
+const SYNC_POINTS: usize = 4;
+
+pub const MIN_SALT_LEN: usize = 8;
+pub const MIN_OUTPUT_LEN: usize = 4;
+// Topes para parámetros que llegan de cadenas PHC sin validar: 4 GiB de memoria
+// (el mismo límite que scrypt) y 1024 pasadas.
+pub const MAX_MEMORY_COST: u32 = 4 * 1024 * 1024;
+pub const MAX_TIME_COST: u32 = 1024;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Argon2Error {
+    InvalidMemoryCost(u32),
+    InvalidTimeCost(u32),
+    InvalidParallelism(u32),
+    InvalidOutputLength(usize),
+    InvalidSaltLength(usize),
+    InvalidEncoding,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Variant {
+    Argon2d,
+    Argon2i,
+    Argon2id,
+}
+
+impl Variant {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Variant::Argon2d => "argon2d",
+            Variant::Argon2i => "argon2i",
+            Variant::Argon2id => "argon2id",
+        }
+    }
+
+    fn type_id(&self) -> u32 {
+        match self {
+            Variant::Argon2d => 0,
+            Variant::Argon2i => 1,
+            Variant::Argon2id => 2,
+        }
+    }
+}
+
+/// Argon2 costs: memory in KiB, number of passes, lanes and tag length in bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Argon2Params {
+    memory_cost: u32,
+    time_cost: u32,
+    parallelism: u32,
+    output_len: usize,
+}
+
+impl Argon2Params {
+    pub fn new(memory_cost: u32, time_cost: u32, parallelism: u32, output_len: usize) -> Result<Self, Argon2Error> {
+        if parallelism == 0 || parallelism > 0x00ffffff {
+            return Err(Argon2Error::InvalidParallelism(parallelism));
+        }
+        if (memory_cost as u64) < 8 * parallelism as u64 || memory_cost > MAX_MEMORY_COST {
+            return Err(Argon2Error::InvalidMemoryCost(memory_cost));
+        }
+        if time_cost == 0 || time_cost > MAX_TIME_COST {
+            return Err(Argon2Error::InvalidTimeCost(time_cost));
+        }
+        if output_len < MIN_OUTPUT_LEN || output_len as u64 > u32::MAX as u64 {
+            return Err(Argon2Error::InvalidOutputLength(output_len));
+        }
+        Ok(Argon2Params {
+            memory_cost,
+            time_cost,
+            parallelism,
+            output_len,
+        })
+    }
+
+    pub fn memory_cost(&self) -> u32 {
+        self.memory_cost
+    }
+
+    pub fn time_cost(&self) -> u32 {
+        self.time_cost
+    }
+
+    pub fn parallelism(&self) -> u32 {
+        self.parallelism
+    }
+
+    pub fn output_len(&self) -> usize {
+        self.output_len
+    }
+}
+
+/// Argon2 (RFC 9106) version 0x13, with optional secret key and associated data.
+#[derive(Clone)]
+pub struct Argon2 {
+    variant: Variant,
+    params: Argon2Params,
+    secret: Vec<u8>,
+    associated_data: Vec<u8>,
+}
+
+impl Argon2 {
+    pub fn new(variant: Variant, params: Argon2Params) -> Self {
+        Argon2::with_secret(variant, params, &[], &[])
+    }
+
+    pub fn with_secret(variant: Variant, params: Argon2Params, secret: &[u8], associated_data: &[u8]) -> Self {
+        Argon2 {
+            variant,
+            params,
+            secret: secret.to_vec(),
+            associated_data: associated_data.to_vec(),
+        }
+    }
+
+    pub fn variant(&self) -> Variant {
+        self.variant
+    }
+
+    pub fn params(&self) -> &Argon2Params {
+        &self.params
+    }
+
+    pub fn hash(&self, password: &[u8], salt: &[u8]) -> Result<Vec<u8>, Argon2Error> {
+        if salt.len() < MIN_SALT_LEN {
+            return Err(Argon2Error::InvalidSaltLength(salt.len()));
+        }
+
+        let lanes = self.params.parallelism as usize;
+        // m' = 4p * floor(m / 4p): cada carril tiene q columnas en 4 segmentos.
+        let lane_len = self.params.memory_cost as usize / (SYNC_POINTS * lanes) * SYNC_POINTS;
+        let segment_len = lane_len / SYNC_POINTS;
+
+        let h0 = self.initial_hash(password, salt);
+        let mut memory = vec![Block::zero(); lanes * lane_len];
+
+        for lane in 0..lanes {
+            for column in 0..2 {
+                let mut input = h0.to_vec();
+                input.extend_from_slice(&(column as u32).to_le_bytes());
+                input.extend_from_slice(&(lane as u32).to_le_bytes());
+                memory[lane * lane_len + column] = Block::from_bytes(&variable_hash(&input, BLOCK_BYTES));
+            }
+        }
+
+        let mut position = Position {
+            pass: 0,
+            lane: 0,
+            slice: 0,
+            lanes,
+            lane_len,
+            segment_len,
+        };
+        for pass in 0..self.params.time_cost as usize {
+            position.pass = pass;
+            for slice in 0..SYNC_POINTS {
+                position.slice = slice;
+                for lane in 0..lanes {
+                    position.lane = lane;
+                    self.fill_segment(&mut memory, &position);
+                }
+            }
+        }
+
+        // El bloque final es el XOR de la última columna de todos los carriles.
+        let mut last = memory[lane_len - 1];
+        for lane in 1..lanes {
+            last.xor_with(&memory[lane * lane_len + lane_len - 1]);
+        }
+        Ok(variable_hash(&last.to_bytes(), self.params.output_len))
+    }
+
+    // H0 = BLAKE2b-512 de los parámetros y de cada entrada precedida de su longitud.
+    fn initial_hash(&self, password: &[u8], salt: &[u8]) -> Vec<u8> {
+        let mut hasher = Blake2b::new(64).expect("64 is a valid BLAKE2b length");
+        for value in [
+            self.params.parallelism,
+            self.params.output_len as u32,
+            self.params.memory_cost,
+            self.params.time_cost,
+            VERSION,
+            self.variant.type_id(),
+        ] {
+            hasher.update(&value.to_le_bytes());
+        }
+        for field in [password, salt, &self.secret, &self.associated_data] {
+            hasher.update(&(field.len() as u32).to_le_bytes());
+            hasher.update(field);
+        }
+        hasher.finalize_variable()
+    }
+
+    fn data_independent(&self, position: &Position) -> bool {
+        match self.variant {
+            Variant::Argon2d => false,
+            Variant::Argon2i => true,
+            // Argon2id solo usa direcciones independientes en la primera mitad del primer pase.
+            Variant::Argon2id => position.pass == 0 && position.slice < SYNC_POINTS / 2,
+        }
+    }
+
+    fn fill_segment(&self, memory: &mut [Block], position: &Position) {
+        let data_independent = self.data_independent(position);
+        let mut address_input = Block::zero();
+        let mut addresses = Block::zero();
+        if data_independent {
+            for (index, value) in [
+                position.pass,
+                position.lane,
+                position.slice,
+                memory.len(),
+                self.params.time_cost as usize,
+                self.variant.type_id() as usize,
+            ]
+            .into_iter()
+            .enumerate()
+            {
+                address_input.set_word(index, value as u64);
+            }
+        }
+
+        // Los dos primeros bloques de cada carril ya vienen de H0.
+        let first = if position.pass == 0 && position.slice == 0 { 2 } else { 0 };
+        if data_independent && first == 2 {
+            next_addresses(&mut address_input, &mut addresses);
+        }
+
+        let lane_start = position.lane * position.lane_len;
+        for index in first..position.segment_len {
+            let column = position.slice * position.segment_len + index;
+            let previous = if column == 0 { position.lane_len - 1 } else { column - 1 };
+
+            let pseudo_random = if data_independent {
+                if index % BLOCK_WORDS == 0 {
+                    next_addresses(&mut address_input, &mut addresses);
+                }
+                addresses.word(index % BLOCK_WORDS)
+            } else {
+                memory[lane_start + previous].word(0)
+            };
+
+            let reference = position.reference_index(index, pseudo_random);
+            let new_block = Block::compress(&memory[lane_start + previous], &memory[reference]);
+            let current = &mut memory[lane_start + column];
+            if position.pass == 0 {
+                *current = new_block;
+            } else {
+                current.xor_with(&new_block);
+            }
+        }
+    }
+}
+
+struct Position {
+    pass: usize,
+    lane: usize,
+    slice: usize,
+    lanes: usize,
+    lane_len: usize,
+    segment_len: usize,
+}
+
+impl Position {
+    // Traduce J1 || J2 al índice absoluto del bloque de referencia (RFC 9106, 3.4.1.2).
+    fn reference_index(&self, index: usize, pseudo_random: u64) -> usize {
+        let j1 = pseudo_random & 0xffffffff;
+        let j2 = pseudo_random >> 32;
+
+        let ref_lane = if self.pass == 0 && self.slice == 0 {
+            self.lane
+        } else {
+            j2 as usize % self.lanes
+        };
+        let same_lane = ref_lane == self.lane;
+
+        let area_size = match (self.pass, same_lane) {
+            (0, true) => self.slice * self.segment_len + index - 1,
+            (0, false) => self.slice * self.segment_len - usize::from(index == 0),
+            (_, true) => self.lane_len - self.segment_len + index - 1,
+            (_, false) => self.lane_len - self.segment_len - usize::from(index == 0),
+        };
+
+        let x = (j1 * j1) >> 32;
+        let y = (area_size as u64 * x) >> 32;
+        let relative = area_size - 1 - y as usize;
+
+        let start = if self.pass == 0 {
+            0
+        } else {
+            (self.slice + 1) * self.segment_len % self.lane_len
+        };
+        ref_lane * self.lane_len + (start + relative) % self.lane_len
+    }
+}
+
+// Siguiente bloque de direcciones: G(0, G(0, Z)) con el contador de Z incrementado.
+fn next_addresses(input: &mut Block, addresses: &mut Block) {
+    input.set_word(6, input.word(6) + 1);
+    let zero = Block::zero();
+    *addresses = Block::compress(&zero, &Block::compress(&zero, input));
+}
+
+// H' de RFC 9106: BLAKE2b de longitud variable encadenando mitades de 32 bytes.
+fn variable_hash(input: &[u8], len: usize) -> Vec<u8> {
+    let prefix = (len as u32).to_le_bytes();
+    if len <= 64 {
+        let mut hasher = Blake2b::new(len).expect("length checked above");
+        hasher.update(&prefix);
+        hasher.update(input);
+        return hasher.finalize_variable();
+    }
+
+    // r = ceil(T / 32) - 2 eslabones completos; de cada uno se toman sus primeros 32 bytes.
+    let links = len.div_ceil(32) - 2;
+    let mut hasher = Blake2b::new(64).expect("64 is a valid BLAKE2b length");
+    hasher.update(&prefix);
+    hasher.update(input);
+    let mut v = hasher.finalize_variable();
+
+    let mut output = Vec::with_capacity(len);
+    for _ in 1..links {
+        output.extend_from_slice(&v[..32]);
+        let mut hasher = Blake2b::new(64).expect("64 is a valid BLAKE2b length");
+        hasher.update(&v);
+        v = hasher.finalize_variable();
+    }
+    output.extend_from_slice(&v[..32]);
+
+    // El último eslabón usa exactamente la longitud que falta.
+    let mut hasher = Blake2b::new(len - 32 * links).expect("tail length is between 33 and 64");
+    hasher.update(&v);
+    output.extend_from_slice(&hasher.finalize_variable());
+    output
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn to_hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+    }
+
+    fn rfc9106_tag(variant: Variant) -> String {
+        let params = Argon2Params::new(32, 3, 4, 32).unwrap();
+        let argon2 = Argon2::with_secret(variant, params, &[0x03; 8], &[0x04; 12]);
+        to_hex(&argon2.hash(&[0x01; 32], &[0x02; 16]).unwrap())
+    }
+
+    #[test]
+    fn test_rfc9106_vectors() {
+        assert_eq!(rfc9106_tag(Variant::Argon2d), "512b391b6f1162975371d30919734294f868e3be3984f3c1a13a4db9fabe4acb");
+        assert_eq!(rfc9106_tag(Variant::Argon2i), "c814d9d1dc7f37aa13f0d77f2494bda1c8de6b016dd388d29952a4c4672b6ce8");
+        assert_eq!(rfc9106_tag(Variant::Argon2id), "0d640df58d78766c08c037a34a8b53c9d01ef0452d75b65eb52520e96b01e659");
+    }
+
+    #[test]
+    fn test_tag_longer_than_blake2b_output() {
+        let params = Argon2Params::new(16, 1, 1, 100).unwrap();
+        let tag = Argon2::new(Variant::Argon2d, params).hash(b"pw", b"saltsalt").unwrap();
+        assert_eq!(
+            to_hex(&tag),
+            "12de5430c3adbb90d3189caeda55c7736c0ee97442bacd24991ca462836096160ea8bbae1e0dafe73349914b2a3264d9\
+             739c037fd1d679593d54c567855d83a762dd7cfffb6568e4c5eb8bbeee7f79620efc46c989e1962d7c47dc6df717c0197960d749"
+        );
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        assert_eq!(Argon2Params::new(32, 3, 0, 32), Err(Argon2Error::InvalidParallelism(0)));
+        assert_eq!(Argon2Params::new(31, 3, 4, 32), Err(Argon2Error::InvalidMemoryCost(31)));
+        assert_eq!(Argon2Params::new(32, 0, 4, 32), Err(Argon2Error::InvalidTimeCost(0)));
+        assert_eq!(Argon2Params::new(MAX_MEMORY_COST + 1, 3, 4, 32), Err(Argon2Error::InvalidMemoryCost(MAX_MEMORY_COST + 1)));
+        assert_eq!(Argon2Params::new(32, MAX_TIME_COST + 1, 4, 32), Err(Argon2Error::InvalidTimeCost(MAX_TIME_COST + 1)));
+        assert_eq!(Argon2Params::new(32, 3, 4, 3), Err(Argon2Error::InvalidOutputLength(3)));
+
+        let params = Argon2Params::new(32, 3, 4, 32).unwrap();
+        assert_eq!(
+            Argon2::new(Variant::Argon2id, params).hash(b"password", b"short"),
+            Err(Argon2Error::InvalidSaltLength(5))
+        );
+    }
+}
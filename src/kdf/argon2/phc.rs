@@ -0,0 +1,184 @@
+use rand::RngCore;
+
+use crate::hash::output::constant_time_eq;
+use crate::kdf::argon2::{Argon2, Argon2Error, Argon2Params, Variant, VERSION};
+
+const BASE64_ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const DEFAULT_SALT_LEN: usize = 16;
+
+impl Argon2 {
+    /// `$argon2id$v=19$m=...,t=...,p=...$salt$hash`, with a fresh random salt.
+    pub fn hash_password(&self, password: &[u8]) -> Result<String, Argon2Error> {
+        let mut salt = [0u8; DEFAULT_SALT_LEN];
+        rand::thread_rng().fill_bytes(&mut salt);
+        self.hash_encoded(password, &salt)
+    }
+
+    pub fn hash_encoded(&self, password: &[u8], salt: &[u8]) -> Result<String, Argon2Error> {
+        let hash = self.hash(password, salt)?;
+        Ok(format!(
+            "${}$v={}$m={},t={},p={}${}${}",
+            self.variant.name(),
+            VERSION,
+            self.params.memory_cost(),
+            self.params.time_cost(),
+            self.params.parallelism(),
+            encode_base64(salt),
+            encode_base64(&hash)
+        ))
+    }
+}
+
+/// Recomputes the hash with the parameters stored in a PHC string and compares in constant time.
+pub fn verify_password(encoded: &str, password: &[u8]) -> Result<bool, Argon2Error> {
+    let (argon2, salt, expected) = parse(encoded)?;
+    let hash = argon2.hash(password, &salt)?;
+    Ok(constant_time_eq(&hash, &expected))
+}
+
+fn parse(encoded: &str) -> Result<(Argon2, Vec<u8>, Vec<u8>), Argon2Error> {
+    let fields: Vec<&str> = encoded.split('$').collect();
+    let [empty, variant, version, costs, salt, hash] = fields[..] else {
+        return Err(Argon2Error::InvalidEncoding);
+    };
+    if !empty.is_empty() || version != format!("v={}", VERSION) {
+        return Err(Argon2Error::InvalidEncoding);
+    }
+
+    let variant = match variant {
+        "argon2d" => Variant::Argon2d,
+        "argon2i" => Variant::Argon2i,
+        "argon2id" => Variant::Argon2id,
+        _ => return Err(Argon2Error::InvalidEncoding),
+    };
+
+    let mut values = [0u32; 3];
+    let costs: Vec<&str> = costs.split(',').collect();
+    if costs.len() != 3 {
+        return Err(Argon2Error::InvalidEncoding);
+    }
+    for ((cost, name), value) in costs.iter().zip(["m=", "t=", "p="]).zip(values.iter_mut()) {
+        *value = cost
+            .strip_prefix(name)
+            .and_then(|digits| digits.parse().ok())
+            .ok_or(Argon2Error::InvalidEncoding)?;
+    }
+
+    let salt = decode_base64(salt).ok_or(Argon2Error::InvalidEncoding)?;
+    let hash = decode_base64(hash).ok_or(Argon2Error::InvalidEncoding)?;
+    let params = Argon2Params::new(values[0], values[1], values[2], hash.len())?;
+    Ok((Argon2::new(variant, params), salt, hash))
+}
+
+// Base64 estándar sin relleno, como exige el formato PHC.
+fn encode_base64(data: &[u8]) -> String {
+    let mut result = String::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let b0 = chunk[0] as u32;
+        let b1 = chunk.get(1).copied().unwrap_or(0) as u32;
+        let b2 = chunk.get(2).copied().unwrap_or(0) as u32;
+        let triple = (b0 << 16) | (b1 << 8) | b2;
+
+        for i in 0..=chunk.len() {
+            let index = (triple >> (18 - 6 * i)) & 0x3F;
+            result.push(BASE64_ALPHABET[index as usize] as char);
+        }
+    }
+    result
+}
+
+fn decode_base64(text: &str) -> Option<Vec<u8>> {
+    if text.len() % 4 == 1 {
+        return None;
+    }
+    let mut bytes = Vec::with_capacity(text.len() * 3 / 4);
+    let mut accumulator = 0u32;
+    let mut bits = 0;
+    for ch in text.bytes() {
+        let value = BASE64_ALPHABET.iter().position(|&symbol| symbol == ch)? as u32;
+        accumulator = (accumulator << 6) | value;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((accumulator >> bits) as u8);
+        }
+    }
+    // Los bits sobrantes del último carácter deben ser cero.
+    if accumulator & ((1 << bits) - 1) != 0 {
+        return None;
+    }
+    Some(bytes)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encoded_hash_matches_reference() {
+        let params = Argon2Params::new(64, 2, 1, 32).unwrap();
+        let encoded = Argon2::new(Variant::Argon2id, params).hash_encoded(b"password", b"somesaltsomesalt").unwrap();
+        assert_eq!(
+            encoded,
+            "$argon2id$v=19$m=64,t=2,p=1$c29tZXNhbHRzb21lc2FsdA$Gpj7qOY5RCXJvcMzqcdQqvgR3wcPX7SleI4c9NtXk6E"
+        );
+    }
+
+    #[test]
+    fn test_verify_reference_string() {
+        let encoded = "$argon2i$v=19$m=256,t=1,p=2$AAECAwQFBgcI$XmJwniNAaCPF92CbvBN4c1bXTL59Eogx";
+        assert_eq!(verify_password(encoded, b"hunter2"), Ok(true));
+        assert_eq!(verify_password(encoded, b"hunter3"), Ok(false));
+    }
+
+    #[test]
+    fn test_random_salt_round_trip() {
+        let argon2 = Argon2::new(Variant::Argon2id, Argon2Params::new(32, 1, 2, 16).unwrap());
+        let first = argon2.hash_password(b"correct horse").unwrap();
+        let second = argon2.hash_password(b"correct horse").unwrap();
+        assert_ne!(first, second);
+        assert!(first.starts_with("$argon2id$v=19$m=32,t=1,p=2$"));
+        assert_eq!(verify_password(&first, b"correct horse"), Ok(true));
+        assert_eq!(verify_password(&second, b"battery staple"), Ok(false));
+    }
+
+    #[test]
+    fn test_malformed_strings() {
+        for encoded in [
+            "",
+            "$argon2x$v=19$m=64,t=2,p=1$c29tZXNhbHQ$aGFzaGhhc2g",
+            "$argon2id$v=16$m=64,t=2,p=1$c29tZXNhbHQ$aGFzaGhhc2g",
+            "$argon2id$v=19$t=2,m=64,p=1$c29tZXNhbHQ$aGFzaGhhc2g",
+            "$argon2id$v=19$m=64,t=2,p=1$c29t*XNhbHQ$aGFzaGhhc2g",
+            "$argon2id$v=19$m=64,t=2,p=1$c29tZXNhbHQ",
+        ] {
+            assert_eq!(verify_password(encoded, b"password"), Err(Argon2Error::InvalidEncoding));
+        }
+        assert_eq!(
+            verify_password("$argon2id$v=19$m=64,t=0,p=1$c29tZXNhbHQ$aGFzaGhhc2g", b"password"),
+            Err(Argon2Error::InvalidTimeCost(0))
+        );
+    }
+
+    #[test]
+    fn test_oversized_costs_rejected_before_hashing() {
+        assert_eq!(
+            verify_password("$argon2id$v=19$m=4294967295,t=4294967295,p=1$c29tZXNhbHQ$aGFzaGhhc2g", b"password"),
+            Err(Argon2Error::InvalidMemoryCost(u32::MAX))
+        );
+        assert_eq!(
+            verify_password("$argon2id$v=19$m=64,t=4294967295,p=1$c29tZXNhbHQ$aGFzaGhhc2g", b"password"),
+            Err(Argon2Error::InvalidTimeCost(u32::MAX))
+        );
+    }
+
+    #[test]
+    fn test_base64_without_padding() {
+        assert_eq!(encode_base64(b"f"), "Zg");
+        assert_eq!(encode_base64(b"fo"), "Zm8");
+        assert_eq!(encode_base64(b"foo"), "Zm9v");
+        assert_eq!(decode_base64("Zm9vYg"), Some(b"foob".to_vec()));
+        assert_eq!(decode_base64("Zm9vYh"), None);
+    }
+}
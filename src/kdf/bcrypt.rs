@@ -0,0 +1,207 @@
+use rand::RngCore;
+
+use crate::block::blowfish::eks::eks_blowfish_setup;
+use crate::hash::output::constant_time_eq;
+
+// bcrypt usa su propio alfabeto base64, sin relleno.
+const BCRYPT_ALPHABET: &[u8; 64] = b"./ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+const MAGIC: &[u8; 24] = b"OrpheanBeholderScryDoubt";
+
+pub const MIN_COST: u32 = 4;
+pub const MAX_COST: u32 = 31;
+pub const DEFAULT_COST: u32 = 12;
+const MAX_PASSWORD_LEN: usize = 72;
+const SALT_LEN: usize = 16;
+const HASH_LEN: usize = 23;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum BcryptError {
+    InvalidCost(u32),
+    InvalidEncoding,
+}
+
+/// `$2b$<cost>$<salt><hash>` with a fresh random 16-byte salt.
+pub fn hash_password(password: &[u8], cost: u32) -> Result<String, BcryptError> {
+    let mut salt = [0u8; SALT_LEN];
+    rand::thread_rng().fill_bytes(&mut salt);
+    hash_with_salt(password, cost, &salt)
+}
+
+pub fn hash_with_salt(password: &[u8], cost: u32, salt: &[u8; SALT_LEN]) -> Result<String, BcryptError> {
+    if !(MIN_COST..=MAX_COST).contains(&cost) {
+        return Err(BcryptError::InvalidCost(cost));
+    }
+    let hash = bcrypt_raw(password, cost, salt);
+    Ok(format!("$2b${:02}${}{}", cost, encode_base64(salt), encode_base64(&hash)))
+}
+
+/// Accepts the `$2a$`, `$2b$` and `$2y$` prefixes and compares in constant time.
+pub fn verify(password: &[u8], encoded: &str) -> Result<bool, BcryptError> {
+    let (cost, salt, expected) = parse(encoded)?;
+    if !(MIN_COST..=MAX_COST).contains(&cost) {
+        return Err(BcryptError::InvalidCost(cost));
+    }
+    let hash = bcrypt_raw(password, cost, &salt);
+    Ok(constant_time_eq(&hash, &expected))
+}
+
+fn bcrypt_raw(password: &[u8], cost: u32, salt: &[u8; SALT_LEN]) -> [u8; HASH_LEN] {
+    // La clave incluye el terminador nulo y se trunca a 72 bytes.
+    let mut key = password.to_vec();
+    key.push(0);
+    key.truncate(MAX_PASSWORD_LEN);
+
+    let state = eks_blowfish_setup(cost, salt, &key);
+
+    let mut words = [0u32; 6];
+    for (word, bytes) in words.iter_mut().zip(MAGIC.chunks_exact(4)) {
+        *word = u32::from_be_bytes(bytes.try_into().unwrap());
+    }
+    for _ in 0..64 {
+        for pair in words.chunks_exact_mut(2) {
+            (pair[0], pair[1]) = state.encrypt_words(pair[0], pair[1]);
+        }
+    }
+
+    // Solo se codifican 23 de los 24 bytes del texto cifrado.
+    let mut hash = [0u8; HASH_LEN];
+    for (chunk, word) in hash.chunks_mut(4).zip(words) {
+        chunk.copy_from_slice(&word.to_be_bytes()[..chunk.len()]);
+    }
+    hash
+}
+
+fn parse(encoded: &str) -> Result<(u32, [u8; SALT_LEN], Vec<u8>), BcryptError> {
+    let fields: Vec<&str> = encoded.split('$').collect();
+    let [empty, version, cost, rest] = fields[..] else {
+        return Err(BcryptError::InvalidEncoding);
+    };
+    if !empty.is_empty() || !matches!(version, "2a" | "2b" | "2y") || cost.len() != 2 || rest.len() != 53 {
+        return Err(BcryptError::InvalidEncoding);
+    }
+    // Con caracteres multibyte `split_at(22)` podría caer en mitad de uno.
+    if !rest.is_ascii() {
+        return Err(BcryptError::InvalidEncoding);
+    }
+    // `parse` aceptaría "+4"; solo se admiten dos dígitos.
+    if !cost.bytes().all(|byte| byte.is_ascii_digit()) {
+        return Err(BcryptError::InvalidEncoding);
+    }
+    let cost = cost.parse().map_err(|_| BcryptError::InvalidEncoding)?;
+
+    let (salt, hash) = rest.split_at(22);
+    let salt = decode_base64(salt)
+        .and_then(|salt| salt.try_into().ok())
+        .ok_or(BcryptError::InvalidEncoding)?;
+    let hash = decode_base64(hash).ok_or(BcryptError::InvalidEncoding)?;
+    Ok((cost, salt, hash))
+}
+
+fn encode_base64(data: &[u8]) -> String {
+    let mut result = String::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let b0 = chunk[0] as u32;
+        let b1 = chunk.get(1).copied().unwrap_or(0) as u32;
+        let b2 = chunk.get(2).copied().unwrap_or(0) as u32;
+        let triple = (b0 << 16) | (b1 << 8) | b2;
+
+        for i in 0..=chunk.len() {
+            let index = (triple >> (18 - 6 * i)) & 0x3F;
+            result.push(BCRYPT_ALPHABET[index as usize] as char);
+        }
+    }
+    result
+}
+
+fn decode_base64(text: &str) -> Option<Vec<u8>> {
+    if text.len() % 4 == 1 {
+        return None;
+    }
+    let mut bytes = Vec::with_capacity(text.len() * 3 / 4);
+    let mut accumulator = 0u32;
+    let mut bits = 0;
+    for ch in text.bytes() {
+        let value = BCRYPT_ALPHABET.iter().position(|&symbol| symbol == ch)? as u32;
+        accumulator = (accumulator << 6) | value;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((accumulator >> bits) as u8);
+        }
+    }
+    // Los bits sobrantes del último carácter deben ser cero, o habría varias codificaciones del mismo valor.
+    if accumulator & ((1 << bits) - 1) != 0 {
+        return None;
+    }
+    Some(bytes)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_known_hashes() {
+        assert_eq!(
+            hash_with_salt(b"password", 4, b"0123456789abcdef").unwrap(),
+            "$2b$04$KBCwKxOzLha2MUDgW0PjXehyC7kcbJmICs4eWpZZOlh/QJzfSPPHe"
+        );
+        assert_eq!(
+            hash_with_salt(b"", 5, &[0u8; 16]).unwrap(),
+            "$2b$05$......................J2ihDv8vVf7QZ9BsaRrKyqs2tkn55Yq"
+        );
+    }
+
+    #[test]
+    fn test_verify_legacy_prefix() {
+        let encoded = "$2a$05$CCCCCCCCCCCCCCCCCCCCC.E5YPO9kmyuRGyh0XouQYb4YMJKvyOeW";
+        assert_eq!(verify(b"U*U", encoded), Ok(true));
+        assert_eq!(verify(b"U*V", encoded), Ok(false));
+    }
+
+    #[test]
+    fn test_password_truncated_to_72_bytes() {
+        let expected = "$2b$04$/uaF/uaF/uaF/uaF/uaF/u1VyqlrbA0wm3YE5mSLANMC4rhiQyiQW";
+        assert_eq!(hash_with_salt(&[b'x'; 72], 4, &[7u8; 16]).unwrap(), expected);
+        assert_eq!(verify(&[b'x'; 80], expected), Ok(true));
+    }
+
+    #[test]
+    fn test_random_salt_round_trip() {
+        let first = hash_password(b"hunter2", MIN_COST).unwrap();
+        let second = hash_password(b"hunter2", MIN_COST).unwrap();
+        assert_ne!(first, second);
+        assert_eq!(verify(b"hunter2", &first), Ok(true));
+        assert_eq!(verify(b"hunter3", &second), Ok(false));
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        assert_eq!(hash_password(b"password", 3), Err(BcryptError::InvalidCost(3)));
+        assert_eq!(hash_password(b"password", 32), Err(BcryptError::InvalidCost(32)));
+        assert_eq!(verify(b"password", "$2x$04$KBCwKxOzLha2MUDgW0PjXehyC7kcbJmICs4eWpZZOlh/QJzfSPPHe"), Err(BcryptError::InvalidEncoding));
+        assert_eq!(verify(b"password", "$2b$04$KBCwKxOzLha2MUDgW0PjXe"), Err(BcryptError::InvalidEncoding));
+        assert_eq!(verify(b"password", "$2b$03$KBCwKxOzLha2MUDgW0PjXehyC7kcbJmICs4eWpZZOlh/QJzfSPPHe"), Err(BcryptError::InvalidCost(3)));
+    }
+
+    #[test]
+    fn test_non_ascii_tail_rejected() {
+        // 53 bytes con una "é" que ocupa los bytes 21 y 22.
+        let rest = format!("{}é{}", "a".repeat(21), "a".repeat(30));
+        assert_eq!(rest.len(), 53);
+        assert_eq!(verify(b"password", &format!("$2b$04${}", rest)), Err(BcryptError::InvalidEncoding));
+    }
+
+    #[test]
+    fn test_non_canonical_encodings_rejected() {
+        let canonical = "$2b$04$KBCwKxOzLha2MUDgW0PjXehyC7kcbJmICs4eWpZZOlh/QJzfSPPHe";
+        assert_eq!(verify(b"password", canonical), Ok(true));
+        // Coste con signo.
+        assert_eq!(verify(b"password", &canonical.replacen("$04$", "$+4$", 1)), Err(BcryptError::InvalidEncoding));
+        // Último carácter de la sal con bits sobrantes distintos de cero.
+        assert_eq!(verify(b"password", &canonical.replacen("PjXe", "PjXf", 1)), Err(BcryptError::InvalidEncoding));
+        // Lo mismo en el último carácter del hash.
+        assert_eq!(verify(b"password", &format!("{}f", &canonical[..canonical.len() - 1])), Err(BcryptError::InvalidEncoding));
+    }
+}
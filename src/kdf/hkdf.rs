@@ -0,0 +1,118 @@
+use std::marker::PhantomData;
+
+use crate::hash::Digest;
+use crate::mac::Hmac;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum HkdfError {
+    InvalidPrkLength(usize),
+    InvalidOutputLength(usize),
+}
+
+/// HKDF (RFC 5869): `extract` condenses the input keying material into a
+/// pseudorandom key, `expand` stretches it into as many bytes as needed.
+pub struct Hkdf<H: Digest> {
+    prk: Vec<u8>,
+    hash: PhantomData<H>,
+}
+
+impl<H: Digest> Hkdf<H> {
+    pub fn extract(salt: &[u8], ikm: &[u8]) -> Self {
+        // Sin sal se usa una cadena de HashLen ceros.
+        let zeros = vec![0u8; H::OUTPUT_SIZE];
+        let salt = if salt.is_empty() { zeros.as_slice() } else { salt };
+        Hkdf {
+            prk: Hmac::<H>::mac(salt, ikm).as_ref().to_vec(),
+            hash: PhantomData,
+        }
+    }
+
+    pub fn from_prk(prk: &[u8]) -> Result<Self, HkdfError> {
+        if prk.len() < H::OUTPUT_SIZE {
+            return Err(HkdfError::InvalidPrkLength(prk.len()));
+        }
+        Ok(Hkdf {
+            prk: prk.to_vec(),
+            hash: PhantomData,
+        })
+    }
+
+    pub fn prk(&self) -> &[u8] {
+        &self.prk
+    }
+
+    pub fn expand(&self, info: &[u8], okm: &mut [u8]) -> Result<(), HkdfError> {
+        if okm.len() > 255 * H::OUTPUT_SIZE {
+            return Err(HkdfError::InvalidOutputLength(okm.len()));
+        }
+
+        // T(i) = HMAC(PRK, T(i-1) || info || i), con T(0) vacío.
+        let mut previous: Vec<u8> = Vec::new();
+        for (i, chunk) in okm.chunks_mut(H::OUTPUT_SIZE).enumerate() {
+            let mut hmac = Hmac::<H>::new(&self.prk);
+            hmac.update(&previous);
+            hmac.update(info);
+            hmac.update(&[(i + 1) as u8]);
+            previous = hmac.finalize().as_ref().to_vec();
+
+            chunk.copy_from_slice(&previous[..chunk.len()]);
+        }
+        Ok(())
+    }
+
+    pub fn derive(salt: &[u8], ikm: &[u8], info: &[u8], len: usize) -> Result<Vec<u8>, HkdfError> {
+        let mut okm = vec![0u8; len];
+        Hkdf::<H>::extract(salt, ikm).expand(info, &mut okm)?;
+        Ok(okm)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hash::{sha1, sha256};
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+    }
+
+    #[test]
+    fn test_rfc5869_case1() {
+        let salt: Vec<u8> = (0x00..=0x0c).collect();
+        let info: Vec<u8> = (0xf0..=0xf9).collect();
+        let hkdf = Hkdf::<sha256::Hasher>::extract(&salt, &[0x0b; 22]);
+        assert_eq!(hex(hkdf.prk()), "077709362c2e32df0ddc3f0dc47bba6390b6c73bb50f9c3122ec844ad7c2b3e5");
+
+        let mut okm = [0u8; 42];
+        hkdf.expand(&info, &mut okm).unwrap();
+        assert_eq!(hex(&okm), "3cb25f25faacd57a90434f64d0362f2a2d2d0a90cf1a5a4c5db02d56ecc4c5bf34007208d5b887185865");
+    }
+
+    #[test]
+    fn test_rfc5869_case3_empty_salt_and_info() {
+        let hkdf = Hkdf::<sha256::Hasher>::extract(&[], &[0x0b; 22]);
+        assert_eq!(hex(hkdf.prk()), "19ef24a32c717b167f33a91d6f648bdf96596776afdb6377ac434c1c293ccb04");
+
+        let okm = Hkdf::<sha256::Hasher>::derive(&[], &[0x0b; 22], &[], 42).unwrap();
+        assert_eq!(hex(&okm), "8da4e775a563c18f715f802a063c5a31b8a11f5c5ee1879ec3454e5f3c738d2d9d201395faa4b61a96c8");
+    }
+
+    #[test]
+    fn test_rfc5869_case4_sha1() {
+        let salt: Vec<u8> = (0x00..=0x0c).collect();
+        let info: Vec<u8> = (0xf0..=0xf9).collect();
+        let okm = Hkdf::<sha1::Hasher>::derive(&salt, &[0x0b; 11], &info, 42).unwrap();
+        assert_eq!(hex(&okm), "085a01ea1b10f36933068b56efa5ad81a4f14b822f5b091568a9cdd4f155fda2c22e422478d305f3f896");
+    }
+
+    #[test]
+    fn test_invalid_lengths() {
+        assert_eq!(Hkdf::<sha256::Hasher>::from_prk(&[0u8; 31]).err(), Some(HkdfError::InvalidPrkLength(31)));
+        assert_eq!(
+            Hkdf::<sha256::Hasher>::derive(&[], b"ikm", &[], 255 * 32 + 1).err(),
+            Some(HkdfError::InvalidOutputLength(255 * 32 + 1))
+        );
+        assert!(Hkdf::<sha256::Hasher>::derive(&[], b"ikm", &[], 255 * 32).is_ok());
+    }
+}
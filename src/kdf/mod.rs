@@ -0,0 +1,10 @@
+pub mod argon2;
+pub mod bcrypt;
+pub mod hkdf;
+pub mod pbkdf2;
+pub mod scrypt;
+
+pub use argon2::{Argon2, Argon2Error, Argon2Params, Variant};
+pub use hkdf::{Hkdf, HkdfError};
+pub use pbkdf2::{pbkdf2, pbkdf2_hmac_sha1, pbkdf2_hmac_sha256, Pbkdf2Error};
+pub use scrypt::{scrypt, ScryptError, ScryptParams};
@@ -0,0 +1,120 @@
+use crate::hash::{sha1, sha256, Digest};
+use crate::mac::Hmac;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Pbkdf2Error {
+    InvalidIterationCount(u32),
+    InvalidOutputLength(usize),
+}
+
+/// PBKDF2 (RFC 8018) with HMAC over `H` as the pseudorandom function.
+pub fn pbkdf2<H: Digest>(password: &[u8], salt: &[u8], iterations: u32, output: &mut [u8]) -> Result<(), Pbkdf2Error> {
+    if iterations == 0 {
+        return Err(Pbkdf2Error::InvalidIterationCount(iterations));
+    }
+    if output.len() as u64 > u32::MAX as u64 * H::OUTPUT_SIZE as u64 {
+        return Err(Pbkdf2Error::InvalidOutputLength(output.len()));
+    }
+
+    // La clave del HMAC es siempre la contraseña: se prepara una vez y se clona.
+    let prf = Hmac::<H>::new(password);
+
+    // T_i = U_1 ^ U_2 ^ ... ^ U_c, con U_1 = PRF(P, S || INT(i)) y U_j = PRF(P, U_{j-1}).
+    for (i, chunk) in output.chunks_mut(H::OUTPUT_SIZE).enumerate() {
+        let mut mac = prf.clone();
+        mac.update(salt);
+        mac.update(&(i as u32 + 1).to_be_bytes());
+        let mut u = mac.finalize();
+        let mut block = u.as_ref().to_vec();
+
+        for _ in 1..iterations {
+            let mut mac = prf.clone();
+            mac.update(u.as_ref());
+            u = mac.finalize();
+            for (acc, byte) in block.iter_mut().zip(u.as_ref()) {
+                *acc ^= byte;
+            }
+        }
+
+        chunk.copy_from_slice(&block[..chunk.len()]);
+    }
+    Ok(())
+}
+
+pub fn pbkdf2_hmac_sha256(password: &[u8], salt: &[u8], iterations: u32, len: usize) -> Result<Vec<u8>, Pbkdf2Error> {
+    let mut output = vec![0u8; len];
+    pbkdf2::<sha256::Hasher>(password, salt, iterations, &mut output)?;
+    Ok(output)
+}
+
+// Solo para compatibilidad con formatos existentes; preferir SHA-256.
+pub fn pbkdf2_hmac_sha1(password: &[u8], salt: &[u8], iterations: u32, len: usize) -> Result<Vec<u8>, Pbkdf2Error> {
+    let mut output = vec![0u8; len];
+    pbkdf2::<sha1::Hasher>(password, salt, iterations, &mut output)?;
+    Ok(output)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::block::des::key::Key;
+
+    fn to_hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+    }
+
+    #[test]
+    fn test_rfc6070_pbkdf2_hmac_sha1() {
+        assert_eq!(
+            to_hex(&pbkdf2_hmac_sha1(b"password", b"salt", 1, 20).unwrap()),
+            "0c60c80f961f0e71f3a9b524af6012062fe037a6"
+        );
+        assert_eq!(
+            to_hex(&pbkdf2_hmac_sha1(b"password", b"salt", 2, 20).unwrap()),
+            "ea6c014dc72d6f8ccd1ed92ace1d41f0d8de8957"
+        );
+        assert_eq!(
+            to_hex(&pbkdf2_hmac_sha1(b"password", b"salt", 4096, 20).unwrap()),
+            "4b007901b765489abead49d926f721d065a429c1"
+        );
+        assert_eq!(
+            to_hex(&pbkdf2_hmac_sha1(b"passwordPASSWORDpassword", b"saltSALTsaltSALTsaltSALTsaltSALTsalt", 4096, 25).unwrap()),
+            "3d2eec4fe41c849b80c8d83662c0e44a8b291a964cf2f07038"
+        );
+        assert_eq!(
+            to_hex(&pbkdf2_hmac_sha1(b"pass\0word", b"sa\0lt", 4096, 16).unwrap()),
+            "56fa6aa75548099dcc37d7f03425e0c3"
+        );
+    }
+
+    #[test]
+    fn test_rfc7914_pbkdf2_hmac_sha256() {
+        assert_eq!(
+            to_hex(&pbkdf2_hmac_sha256(b"passwd", b"salt", 1, 64).unwrap()),
+            "55ac046e56e3089fec1691c22544b605f94185216dde0465e68b9d57c20dacbc\
+             49ca9cccf179b645991664b39d77ef317c71b845b1e30bd509112041d3a19783"
+        );
+        assert_eq!(
+            to_hex(&pbkdf2_hmac_sha256(b"Password", b"NaCl", 80000, 64).unwrap()),
+            "4ddcd8f60b98be21830cee5ef22701f9641a4418d04c0414aeff08876b34ab56\
+             a1d425a1225833549adb841b51c9b3176a272bdebba1d078478f62b397f33c8d"
+        );
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        assert_eq!(
+            pbkdf2_hmac_sha256(b"password", b"salt", 0, 32),
+            Err(Pbkdf2Error::InvalidIterationCount(0))
+        );
+    }
+
+    #[test]
+    fn test_des_key_from_password() {
+        let key = Key::from_password(b"password", b"salt", 1).unwrap();
+        let expected = pbkdf2_hmac_sha256(b"password", b"salt", 1, 8).unwrap();
+        assert_eq!(key.get_data().to_be_bytes().to_vec(), expected);
+        assert_eq!(key.to_hex_string(), "0x120FB6CFFCF8B32C");
+    }
+}
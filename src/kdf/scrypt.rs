@@ -0,0 +1,240 @@
+use crate::kdf::pbkdf2_hmac_sha256;
+
+// Tope de memoria para las tablas V de todas las líneas (p * N * 128 * r bytes), que con
+// rayon se reservan a la vez. Basta para los parámetros de RFC 7914 (1 GiB).
+pub const MAX_MEMORY: u64 = 1 << 32;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ScryptError {
+    InvalidCostParameter(u64),
+    InvalidBlockSize(u32),
+    InvalidParallelization(u32),
+    InvalidOutputLength(usize),
+    MemoryLimitExceeded,
+}
+
+/// scrypt cost parameters: CPU/memory cost `n`, block size `r` and parallelization `p`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScryptParams {
+    n: u64,
+    r: u32,
+    p: u32,
+}
+
+impl ScryptParams {
+    pub fn new(n: u64, r: u32, p: u32) -> Result<Self, ScryptError> {
+        if r == 0 {
+            return Err(ScryptError::InvalidBlockSize(r));
+        }
+        // N debe ser potencia de dos mayor que 1 y menor que 2^(128 r / 8).
+        if n < 2 || !n.is_power_of_two() || (r < 8 && n.trailing_zeros() >= 16 * r) {
+            return Err(ScryptError::InvalidCostParameter(n));
+        }
+        if p == 0 || r as u64 * p as u64 >= 1 << 30 {
+            return Err(ScryptError::InvalidParallelization(p));
+        }
+        match memory_required(n, r, p) {
+            Some(bytes) if bytes as u64 <= MAX_MEMORY => Ok(ScryptParams { n, r, p }),
+            _ => Err(ScryptError::MemoryLimitExceeded),
+        }
+    }
+
+    pub fn n(&self) -> u64 {
+        self.n
+    }
+
+    pub fn r(&self) -> u32 {
+        self.r
+    }
+
+    pub fn p(&self) -> u32 {
+        self.p
+    }
+}
+
+// None si el tamaño no cabe en usize.
+fn memory_required(n: u64, r: u32, p: u32) -> Option<usize> {
+    let lane = usize::try_from(n).ok()?.checked_mul(128)?.checked_mul(r as usize)?;
+    lane.checked_mul(p as usize)
+}
+
+/// scrypt (RFC 7914): PBKDF2-HMAC-SHA256 around `p` independent ROMix lanes.
+pub fn scrypt(password: &[u8], salt: &[u8], params: &ScryptParams, output: &mut [u8]) -> Result<(), ScryptError> {
+    if output.is_empty() {
+        return Err(ScryptError::InvalidOutputLength(0));
+    }
+
+    let n = usize::try_from(params.n).expect("ScryptParams::new bounds the memory cost");
+    let lane_len = 128 * params.r as usize;
+    let mut blocks = pbkdf2_hmac_sha256(password, salt, 1, lane_len * params.p as usize)
+        .map_err(|_| ScryptError::InvalidOutputLength(output.len()))?;
+
+    #[cfg(feature = "rayon")]
+    {
+        use rayon::prelude::*;
+        blocks.par_chunks_mut(lane_len).for_each(|lane| ro_mix(lane, n));
+    }
+    #[cfg(not(feature = "rayon"))]
+    for lane in blocks.chunks_mut(lane_len) {
+        ro_mix(lane, n);
+    }
+
+    let derived = pbkdf2_hmac_sha256(password, &blocks, 1, output.len())
+        .map_err(|_| ScryptError::InvalidOutputLength(output.len()))?;
+    output.copy_from_slice(&derived);
+    Ok(())
+}
+
+// Mezcla secuencial dependiente de memoria sobre un bloque de 128 r bytes.
+fn ro_mix(lane: &mut [u8], n: usize) {
+    let words = lane.len() / 4;
+    let mut x: Vec<u32> = lane
+        .chunks_exact(4)
+        .map(|bytes| u32::from_le_bytes(bytes.try_into().unwrap()))
+        .collect();
+    let mut scratch = vec![0u32; words];
+    let mut v = vec![0u32; n * words];
+
+    for i in 0..n {
+        v[i * words..(i + 1) * words].copy_from_slice(&x);
+        block_mix(&x, &mut scratch);
+        std::mem::swap(&mut x, &mut scratch);
+    }
+
+    for _ in 0..n {
+        // Integerify: la primera palabra del último bloque de 64 bytes, módulo N.
+        let j = x[words - 16] as usize & (n - 1);
+        for (word, saved) in x.iter_mut().zip(&v[j * words..(j + 1) * words]) {
+            *word ^= saved;
+        }
+        block_mix(&x, &mut scratch);
+        std::mem::swap(&mut x, &mut scratch);
+    }
+
+    for (bytes, word) in lane.chunks_exact_mut(4).zip(&x) {
+        bytes.copy_from_slice(&word.to_le_bytes());
+    }
+}
+
+// BlockMix con Salsa20/8: las salidas pares van a la primera mitad y las impares a la segunda.
+fn block_mix(input: &[u32], output: &mut [u32]) {
+    let blocks = input.len() / 16;
+    let mut x: [u32; 16] = input[input.len() - 16..].try_into().unwrap();
+
+    for i in 0..blocks {
+        for (word, block_word) in x.iter_mut().zip(&input[i * 16..(i + 1) * 16]) {
+            *word ^= block_word;
+        }
+        salsa20_8(&mut x);
+
+        let target = if i % 2 == 0 { i / 2 } else { blocks / 2 + i / 2 };
+        output[target * 16..(target + 1) * 16].copy_from_slice(&x);
+    }
+}
+
+fn quarter_round(state: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize) {
+    state[b] ^= state[a].wrapping_add(state[d]).rotate_left(7);
+    state[c] ^= state[b].wrapping_add(state[a]).rotate_left(9);
+    state[d] ^= state[c].wrapping_add(state[b]).rotate_left(13);
+    state[a] ^= state[d].wrapping_add(state[c]).rotate_left(18);
+}
+
+// Núcleo Salsa20 reducido a 8 rondas (4 dobles rondas) con la suma final de la entrada.
+fn salsa20_8(block: &mut [u32; 16]) {
+    let mut state = *block;
+    for _ in 0..4 {
+        // Columnas
+        quarter_round(&mut state, 0, 4, 8, 12);
+        quarter_round(&mut state, 5, 9, 13, 1);
+        quarter_round(&mut state, 10, 14, 2, 6);
+        quarter_round(&mut state, 15, 3, 7, 11);
+        // Filas
+        quarter_round(&mut state, 0, 1, 2, 3);
+        quarter_round(&mut state, 5, 6, 7, 4);
+        quarter_round(&mut state, 10, 11, 8, 9);
+        quarter_round(&mut state, 15, 12, 13, 14);
+    }
+    for (word, mixed) in block.iter_mut().zip(state) {
+        *word = word.wrapping_add(mixed);
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn to_hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+    }
+
+    fn from_hex(hex: &str) -> Vec<u8> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    fn scrypt_hex(password: &[u8], salt: &[u8], n: u64, r: u32, p: u32) -> String {
+        let params = ScryptParams::new(n, r, p).unwrap();
+        let mut output = [0u8; 64];
+        scrypt(password, salt, &params, &mut output).unwrap();
+        to_hex(&output)
+    }
+
+    #[test]
+    fn test_rfc7914_salsa20_8_core() {
+        let input = from_hex(
+            "7e879a214f3ec9867ca940e641718f26baee555b8c61c1b50df846116dcd3b1d\
+             ee24f319df9b3d8514121e4b5ac5aa3276021d2909c74829edebc68db8b8c25e",
+        );
+        let mut block = [0u32; 16];
+        for (word, bytes) in block.iter_mut().zip(input.chunks_exact(4)) {
+            *word = u32::from_le_bytes(bytes.try_into().unwrap());
+        }
+        salsa20_8(&mut block);
+        let output: Vec<u8> = block.iter().flat_map(|word| word.to_le_bytes()).collect();
+        assert_eq!(
+            to_hex(&output),
+            "a41f859c6608cc993b81cacb020cef05044b2181a2fd337dfd7b1c6396682f29\
+             b4393168e3c9e6bcfe6bc5b7a06d96bae424cc102c91745c24ad673dc7618f81"
+        );
+    }
+
+    #[test]
+    fn test_rfc7914_scrypt_vectors() {
+        assert_eq!(
+            scrypt_hex(b"", b"", 16, 1, 1),
+            "77d6576238657b203b19ca42c18a0497f16b4844e3074ae8dfdffa3fede21442\
+             fcd0069ded0948f8326a753a0fc81f17e8d3e0fb2e0d3628cf35e20c38d18906"
+        );
+        assert_eq!(
+            scrypt_hex(b"password", b"NaCl", 1024, 8, 16),
+            "fdbabe1c9d3472007856e7190d01e9fe7c6ad7cbc8237830e77376634b373162\
+             2eaf30d92e22a3886ff109279d9830dac727afb94a83ee6d8360cbdfa2cc0640"
+        );
+        assert_eq!(
+            scrypt_hex(b"pleaseletmein", b"SodiumChloride", 16384, 8, 1),
+            "7023bdcb3afd7348461c06cd81fd38ebfda8fbba904f8e3ea9b543f6545da1f2\
+             d5432955613f0fcf62d49705242a9af9e61e85dc0d651e40dfcf017b45575887"
+        );
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        assert_eq!(ScryptParams::new(1000, 8, 1), Err(ScryptError::InvalidCostParameter(1000)));
+        assert_eq!(ScryptParams::new(1, 8, 1), Err(ScryptError::InvalidCostParameter(1)));
+        assert_eq!(ScryptParams::new(1 << 16, 1, 1), Err(ScryptError::InvalidCostParameter(1 << 16)));
+        assert_eq!(ScryptParams::new(16, 0, 1), Err(ScryptError::InvalidBlockSize(0)));
+        assert_eq!(ScryptParams::new(16, 8, 0), Err(ScryptError::InvalidParallelization(0)));
+
+        assert_eq!(ScryptParams::new(1 << 62, 8, 1), Err(ScryptError::MemoryLimitExceeded));
+        assert_eq!(ScryptParams::new(1 << 40, 8, 1), Err(ScryptError::MemoryLimitExceeded));
+        // Cada línea cabe, pero las p juntas no.
+        assert!(ScryptParams::new(1 << 20, 8, 4).is_ok());
+        assert_eq!(ScryptParams::new(1 << 20, 8, 5), Err(ScryptError::MemoryLimitExceeded));
+
+        let params = ScryptParams::new(16, 1, 1).unwrap();
+        assert_eq!(scrypt(b"", b"", &params, &mut []), Err(ScryptError::InvalidOutputLength(0)));
+    }
+}
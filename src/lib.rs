@@ -1,4 +1,6 @@
 pub mod substitution;
 pub mod block;
 pub mod hash;
+pub mod kdf;
+pub mod mac;
 pub mod pubkey;
\ No newline at end of file
@@ -0,0 +1,141 @@
+use crate::hash::output::constant_time_eq;
+use crate::hash::Digest;
+
+const IPAD: u8 = 0x36;
+const OPAD: u8 = 0x5c;
+
+/// HMAC (RFC 2104) over any hash in `crate::hash` that implements `Digest`.
+#[derive(Clone)]
+pub struct Hmac<H: Digest> {
+    inner: H,
+    outer: H,
+    inner_pad: Vec<u8>,
+}
+
+impl<H: Digest> Hmac<H> {
+    pub fn new(key: &[u8]) -> Self {
+        // Las claves más largas que un bloque se sustituyen por su hash.
+        let mut block_key = if key.len() > H::BLOCK_SIZE {
+            H::digest(key).as_ref().to_vec()
+        } else {
+            key.to_vec()
+        };
+        block_key.resize(H::BLOCK_SIZE, 0x00);
+
+        let inner_pad: Vec<u8> = block_key.iter().map(|byte| byte ^ IPAD).collect();
+        let outer_pad: Vec<u8> = block_key.iter().map(|byte| byte ^ OPAD).collect();
+
+        // Ambos estados ya absorben su bloque de clave; clonar la instancia
+        // evita recalcularlos cuando se reutiliza la misma clave.
+        let mut inner = H::new();
+        inner.update(&inner_pad);
+        let mut outer = H::new();
+        outer.update(&outer_pad);
+
+        Hmac {
+            inner,
+            outer,
+            inner_pad,
+        }
+    }
+
+    pub fn update(&mut self, data: &[u8]) {
+        self.inner.update(data);
+    }
+
+    pub fn finalize(self) -> H::Output {
+        let inner_digest = self.inner.finalize();
+        let mut outer = self.outer;
+        outer.update(inner_digest.as_ref());
+        outer.finalize()
+    }
+
+    pub fn verify(self, tag: &[u8]) -> bool {
+        constant_time_eq(self.finalize().as_ref(), tag)
+    }
+
+    pub fn reset(&mut self) {
+        self.inner.reset();
+        self.inner.update(&self.inner_pad);
+    }
+
+    pub fn mac(key: &[u8], data: &[u8]) -> H::Output {
+        let mut hmac = Hmac::<H>::new(key);
+        hmac.update(data);
+        hmac.finalize()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hash::{md5, sha1, sha256, sha512};
+
+    #[test]
+    fn test_rfc2104_hmac_md5() {
+        assert_eq!(Hmac::<md5::Hasher>::mac(&[0x0b; 16], b"Hi There").to_hex(), "9294727a3638bb1c13f48ef8158bfc9d");
+        assert_eq!(Hmac::<md5::Hasher>::mac(b"Jefe", b"what do ya want for nothing?").to_hex(), "750c783e6ab0b503eaa86e310a5db738");
+        assert_eq!(Hmac::<md5::Hasher>::mac(&[0xaa; 16], &[0xdd; 50]).to_hex(), "56be34521d144c88dbb8c733f0e8b3f6");
+    }
+
+    #[test]
+    fn test_rfc4231_hmac_sha256() {
+        assert_eq!(
+            Hmac::<sha256::Hasher>::mac(&[0x0b; 20], b"Hi There").to_hex(),
+            "b0344c61d8db38535ca8afceaf0bf12b881dc200c9833da726e9376c2e32cff7"
+        );
+        assert_eq!(
+            Hmac::<sha256::Hasher>::mac(b"Jefe", b"what do ya want for nothing?").to_hex(),
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+        assert_eq!(
+            Hmac::<sha256::Hasher>::mac(&[0xaa; 131], b"Test Using Larger Than Block-Size Key - Hash Key First").to_hex(),
+            "60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54"
+        );
+    }
+
+    #[test]
+    fn test_rfc4231_hmac_sha512() {
+        assert_eq!(
+            Hmac::<sha512::Hasher>::mac(&[0x0b; 20], b"Hi There").to_hex(),
+            "87aa7cdea5ef619d4ff0b4241a1d6cb02379f4e2ce4ec2787ad0b30545e17cdedaa833b7d6b8a702038b274eaea3f4e4be9d914eeb61f1702e696c203a126854"
+        );
+        assert_eq!(
+            Hmac::<sha512::Hasher>::mac(&[0xaa; 131], b"Test Using Larger Than Block-Size Key - Hash Key First").to_hex(),
+            "80b24263c7c1a3ebb71493c1dd7be8b49b46d1f41b4aeec1121b013783f8f3526b56d037e05f2598bd0fd2215d6a1e5295e64f73f63f0aec8b915a985d786598"
+        );
+    }
+
+    #[test]
+    fn test_rfc2202_hmac_sha1() {
+        assert_eq!(
+            Hmac::<sha1::Hasher>::mac(b"Jefe", b"what do ya want for nothing?").to_hex(),
+            "effcdf6ae5eb2fa2d27416d5f184df9c259a7c79"
+        );
+    }
+
+    #[test]
+    fn test_streaming_and_verify() {
+        let mut hmac = Hmac::<sha256::Hasher>::new(b"Jefe");
+        hmac.update(b"what do ya ");
+        hmac.update(b"want for nothing?");
+        let tag = Hmac::<sha256::Hasher>::mac(b"Jefe", b"what do ya want for nothing?");
+        assert!(hmac.verify(tag.as_bytes()));
+
+        let mut tampered = tag.into_bytes();
+        tampered[0] ^= 1;
+        let mut hmac = Hmac::<sha256::Hasher>::new(b"Jefe");
+        hmac.update(b"what do ya want for nothing?");
+        assert!(!hmac.verify(&tampered));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut hmac = Hmac::<md5::Hasher>::new(b"Jefe");
+        hmac.update(b"garbage");
+        hmac.reset();
+        hmac.update(b"what do ya want for nothing?");
+        assert_eq!(hmac.finalize().to_hex(), "750c783e6ab0b503eaa86e310a5db738");
+    }
+}
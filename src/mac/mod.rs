@@ -0,0 +1,3 @@
+pub mod hmac;
+
+pub use hmac::Hmac;
@@ -1,7 +1,9 @@
 use rand::Rng;
-use rug::{rand::RandState, Complete, Integer};
+use rug::{integer::Order, rand::RandState, Complete, Integer};
 
 use super::prime_generator;
+use crate::hash::sha256;
+use crate::kdf::{Hkdf, HkdfError};
 
 pub struct DiffieHellmanParams {
     p: Integer,
@@ -45,7 +47,8 @@ impl PublicKey {
     }
 }
 
-pub struct SharedKey(Integer);
+// Valor compartido y longitud en bytes del módulo p.
+pub struct SharedKey(Integer, usize);
 
 impl SharedKey {
     fn compute(private_key: &PrivateKey, other_public_key: &PublicKey, params: &DiffieHellmanParams) -> Self {
@@ -53,7 +56,16 @@ impl SharedKey {
             .pow_mod(&private_key.0, &params.p)
             .unwrap();
 
-        SharedKey(shared_key_value)
+        SharedKey(shared_key_value, (params.p.significant_bits() as usize).div_ceil(8))
+    }
+
+    // El secreto compartido no es uniforme; se pasa por HKDF-SHA256 con una
+    // etiqueta de contexto para separar las claves de cada uso. Como en RFC 2631
+    // y SP 800-56A, se codifica con la longitud de p, con ceros a la izquierda.
+    pub fn derive_key(&self, label: &str, len: usize) -> Result<Vec<u8>, HkdfError> {
+        let mut secret = vec![0u8; self.1];
+        self.0.write_digits(&mut secret, Order::Msf);
+        Hkdf::<sha256::Hasher>::derive(&[], &secret, label.as_bytes(), len)
     }
 }
 
@@ -78,4 +90,41 @@ mod tests {
 
         assert_eq!(shared_key_1.0, shared_key_2.0);
     }
+
+    #[test]
+    fn shared_key_derivation_test() {
+        let params = DiffieHellmanParams::new(512);
+
+        let private_key_1 = PrivateKey::generate(&params);
+        let public_key_1 = PublicKey::generate(&private_key_1, &params);
+        let private_key_2 = PrivateKey::generate(&params);
+        let public_key_2 = PublicKey::generate(&private_key_2, &params);
+
+        let shared_key_1 = SharedKey::compute(&private_key_1, &public_key_2, &params);
+        let shared_key_2 = SharedKey::compute(&private_key_2, &public_key_1, &params);
+
+        let key_1 = shared_key_1.derive_key("des session key", 8).unwrap();
+        let key_2 = shared_key_2.derive_key("des session key", 8).unwrap();
+        assert_eq!(key_1.len(), 8);
+        assert_eq!(key_1, key_2);
+        assert_ne!(key_1, shared_key_1.derive_key("mac key", 8).unwrap());
+    }
+
+    #[test]
+    fn shared_key_keeps_leading_zero_bytes() {
+        // p = 2^64 - 59; con clave privada 1 el secreto es la clave pública tal cual.
+        let params = DiffieHellmanParams {
+            p: Integer::from(0xffff_ffff_ffff_ffc5u64),
+            g: Integer::from(5),
+        };
+        let public_key = PublicKey(Integer::from(0x0012_3456_789a_bcdeu64));
+        let shared_key = SharedKey::compute(&PrivateKey(Integer::from(1)), &public_key, &params);
+
+        let padded = [0x00, 0x12, 0x34, 0x56, 0x78, 0x9a, 0xbc, 0xde];
+        let expected = Hkdf::<sha256::Hasher>::derive(&[], &padded, b"label", 16).unwrap();
+        let minimal = Hkdf::<sha256::Hasher>::derive(&[], &padded[1..], b"label", 16).unwrap();
+        let key = shared_key.derive_key("label", 16).unwrap();
+        assert_eq!(key, expected);
+        assert_ne!(key, minimal);
+    }
 }
\ No newline at end of file